@@ -8,7 +8,8 @@ use fs::DirEntry;
 use tokio::fs;
 
 use crate::{
-    IntoIoError, IntoJsonError, IoError, JsonFileError, LAUNCHER_CACHE_DIR, LAUNCHER_DIR,
+    IntoIoError, IntoJsonError, IoError, JavaVersion, JsonFileError, LAUNCHER_CACHE_DIR,
+    LAUNCHER_DIR, err,
     file_utils::exists,
     info,
     json::{AssetIndex, VersionDetails},
@@ -108,6 +109,79 @@ pub async fn clear_cache_dir() -> Result<u64, IoError> {
     Ok(size)
 }
 
+/// Lists instances in `QuantumLauncher/instances/` that are
+/// missing `config.json`, usually because creating them
+/// was interrupted. These break the instance list.
+///
+/// Note: an instance that's currently being created
+/// also lacks `config.json` until it's done.
+pub async fn find_orphaned_instances() -> Vec<String> {
+    let instances_dir = LAUNCHER_DIR.join("instances");
+    let Ok(mut entries) = fs::read_dir(&instances_dir).await else {
+        return Vec::new();
+    };
+
+    let mut orphaned = Vec::new();
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        if !path.is_dir() || exists(&path.join("config.json")).await {
+            continue;
+        }
+        orphaned.push(entry.file_name().to_string_lossy().to_string());
+    }
+    orphaned
+}
+
+/// Deletes the given instances, as found by
+/// [`find_orphaned_instances`] (and confirmed by the user).
+///
+/// Instances that have gained a `config.json` since then
+/// (eg: they finished being created) are left alone.
+///
+/// Returns the names of the removed instances.
+/// Instances that couldn't be removed are logged and skipped.
+pub async fn remove_orphaned_instances(names: Vec<String>) -> Vec<String> {
+    let instances_dir = LAUNCHER_DIR.join("instances");
+    let mut removed = Vec::new();
+    for name in names {
+        let path = instances_dir.join(&name);
+        if exists(&path.join("config.json")).await {
+            continue;
+        }
+        if let Err(err) = fs::remove_dir_all(&path).await.path(&path) {
+            err!("Could not remove orphaned instance {name}: {err}");
+            continue;
+        }
+        pt!("Removed orphaned instance: {name}");
+        removed.push(name);
+    }
+    removed
+}
+
+/// Deletes Java installs (in `QuantumLauncher/java_installs/`)
+/// that were interrupted midway, marked by a leftover `install.lock`.
+///
+/// They would get reinstalled from scratch anyway,
+/// so this just frees up the wasted space.
+/// Returns the versions that were removed.
+pub async fn remove_orphaned_java_installs() -> Vec<JavaVersion> {
+    let java_installs = LAUNCHER_DIR.join("java_installs");
+    let mut removed = Vec::new();
+    for version in JavaVersion::ALL {
+        let path = java_installs.join(version.to_string());
+        if !exists(&path.join("install.lock")).await {
+            continue;
+        }
+        if let Err(err) = fs::remove_dir_all(&path).await.path(&path) {
+            err!("Could not remove incomplete Java install ({version}): {err}");
+            continue;
+        }
+        pt!("Removed incomplete Java install: {version}");
+        removed.push(*version);
+    }
+    removed
+}
+
 pub async fn size_of_path(p: &Path) -> Result<u64, IoError> {
     let metadata = p.metadata().path(p)?;
     if metadata.is_file() {
@@ -239,7 +313,7 @@ async fn get_used_indexes() -> Result<Vec<String>, JsonFileError> {
         let Ok(json) = serde_json::from_str::<VersionDetails>(&json) else {
             continue;
         };
-        used_files.push(format!("{}.json", json.assetIndex.id));
+        used_files.push(format!("{}.json", json.get_id_for_asset_index()));
     }
 
     Ok(used_files)
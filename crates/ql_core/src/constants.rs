@@ -1,5 +1,12 @@
 use cfg_if::cfg_if;
 
+// `OS_NAME`: Name of the current OS, as shown in logs and
+// error messages (eg: `"linux"`, `"osx"`, `"windows"`, `"freebsd"`).
+// For matching library rules in version JSONs, use
+// `get_mojang_os_name()` instead.
+//
+// `OS_NAMES`: All names the current OS may go by
+// in library natives classifiers (`natives-<name>`).
 cfg_if!(
     if #[cfg(any(feature = "simulate_linux_arm64", feature = "simulate_linux_arm32"))] {
         pub const OS_NAME: &str = "linux";
@@ -22,6 +29,21 @@ cfg_if!(
     }
 );
 
+/// Returns the name of the current OS in the format
+/// Mojang uses in version JSON library rules
+/// (`"windows"`, `"osx"` or `"linux"`).
+///
+/// Use this (rather than [`OS_NAME`], which is meant for
+/// logs and error messages) when matching the `os.name`
+/// field in rules. [`OS_NAME`] currently uses the same names,
+/// and platforms Mojang doesn't know about (like FreeBSD)
+/// keep their own name, so that rules for other OSes
+/// don't match them.
+#[must_use]
+pub const fn get_mojang_os_name() -> &'static str {
+    OS_NAME
+}
+
 pub const DEFAULT_RAM_MB_FOR_INSTANCE: usize = 2048;
 
 cfg_if!(
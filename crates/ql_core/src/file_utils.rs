@@ -3,17 +3,21 @@ use std::{
     ffi::OsStr,
     io::{Cursor, Write},
     path::{MAIN_SEPARATOR, Path, PathBuf},
-    sync::LazyLock,
+    sync::{LazyLock, atomic::AtomicUsize, mpsc::Sender},
 };
 
 use flate2::read::GzDecoder;
 use reqwest::header::InvalidHeaderValue;
 use serde::de::DeserializeOwned;
+use sha1::{Digest, Sha1};
 use thiserror::Error;
 use walkdir::WalkDir;
 use zip::{ZipArchive, ZipWriter, write::FileOptions};
 
-use crate::{IntoIoError, JsonDownloadError, download, error::IoError};
+use crate::{
+    CLIENT, GenericProgress, IntoIoError, JsonDownloadError, download, error::IoError,
+    request::check_for_success,
+};
 
 /// The path to the QuantumLauncher root folder.
 ///
@@ -257,6 +261,173 @@ pub async fn download_file_to_bytes(url: &str, user_agent: bool) -> Result<Vec<u
     r.bytes().await
 }
 
+/// Downloads a file from the given URL into a `Vec<u8>`,
+/// sending the given extra headers.
+///
+/// Useful for sites that reject requests without
+/// a proper `User-Agent` (or need other headers).
+///
+/// # Errors
+/// Returns an error if:
+/// - A header name or value is invalid
+/// - Error sending request
+/// - Request is rejected (HTTP status code)
+/// - Redirect loop detected
+/// - Redirect limit exhausted.
+pub async fn download_file_with_headers(
+    url: &str,
+    headers: &[(&str, &str)],
+) -> Result<Vec<u8>, RequestError> {
+    download(url).headers(headers).bytes().await
+}
+
+/// Downloads a (large) file to `dest`, resuming
+/// a previous partial download if one exists.
+///
+/// If `dest` already exists, a `Range: bytes=<existing_size>-`
+/// header is sent and the rest is appended. If the connection
+/// drops midway, this retries while keeping what's already
+/// been downloaded.
+///
+/// If the server doesn't support range requests,
+/// the file is downloaded from the start.
+///
+/// This doesn't go through the HTTP cache, as partial
+/// responses can't be cached (and large files shouldn't be).
+///
+/// Note: this doesn't know whether an existing file is
+/// *complete*; delete `dest` first if you want a fresh download.
+///
+/// # Errors
+/// Returns an error if:
+/// - Error sending request (after retries)
+/// - Request is rejected (HTTP status code)
+/// - `dest` couldn't be written to
+pub async fn download_with_resume(
+    url: &str,
+    dest: &Path,
+    progress: Option<&Sender<GenericProgress>>,
+) -> Result<(), RequestError> {
+    const LIMIT: usize = 5;
+    let mut result = download_with_resume_inner(url, dest, progress).await;
+    for _ in 0..LIMIT {
+        match &result {
+            Ok(()) | Err(RequestError::DownloadError { .. } | RequestError::Io(_)) => break,
+            Err(_) => {}
+        }
+        result = download_with_resume_inner(url, dest, progress).await;
+    }
+    result
+}
+
+async fn download_with_resume_inner(
+    url: &str,
+    dest: &Path,
+    progress: Option<&Sender<GenericProgress>>,
+) -> Result<(), RequestError> {
+    use futures::StreamExt;
+    use tokio::io::AsyncWriteExt;
+
+    let existing = match tokio::fs::metadata(dest).await {
+        Ok(n) => n.len(),
+        Err(_) => 0,
+    };
+
+    let mut request = CLIENT.get(url);
+    if existing > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={existing}-"));
+    }
+    let response = request.send().await?;
+
+    if existing > 0 && response.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+        // Already fully downloaded
+        return Ok(());
+    }
+    check_for_success(&response)?;
+
+    let is_resumed = response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+    let mut done = if is_resumed { existing } else { 0 };
+    let total = response.content_length().map(|n| n + done);
+
+    if let Some(parent) = dest.parent() {
+        tokio::fs::create_dir_all(parent).await.path(parent)?;
+    }
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(is_resumed)
+        .truncate(!is_resumed)
+        .open(dest)
+        .await
+        .path(dest)?;
+
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk?;
+        file.write_all(&chunk).await.path(dest)?;
+        done += chunk.len() as u64;
+
+        if let (Some(progress), Some(total)) = (progress, total) {
+            _ = progress.send(GenericProgress {
+                done: done as usize,
+                total: total as usize,
+                message: None,
+                has_finished: false,
+            });
+        }
+    }
+    file.flush().await.path(dest)?;
+    Ok(())
+}
+
+/// Downloads a file from the given URL into a `Vec<u8>`,
+/// and verifies it against the expected SHA-1 hash.
+///
+/// # Arguments
+/// - `url`: the URL to download from
+/// - `sha1`: the expected SHA-1 hash (hex string, case-insensitive).
+///   If empty, verification is skipped.
+/// - `user_agent`: whether to use the quantum launcher
+///   user agent (required for modrinth)
+///
+/// # Errors
+/// Returns an error if:
+/// - Error sending request
+/// - Request is rejected (HTTP status code)
+/// - Redirect loop detected
+/// - Redirect limit exhausted.
+/// - The downloaded file doesn't match the hash
+pub async fn download_file_verified(
+    url: &str,
+    sha1: &str,
+    user_agent: bool,
+) -> Result<Vec<u8>, RequestError> {
+    let bytes = download_file_to_bytes(url, user_agent).await?;
+    if sha1.is_empty() {
+        return Ok(bytes);
+    }
+    let got = get_sha1(&bytes);
+    if got.eq_ignore_ascii_case(sha1) {
+        Ok(bytes)
+    } else {
+        Err(RequestError::HashMismatch {
+            url: url.to_owned(),
+            expected: sha1.to_owned(),
+            got,
+        })
+    }
+}
+
+/// Returns the SHA-1 hash of the given bytes,
+/// as a lowercase hex string.
+///
+/// This is the format used by Mojang's JSON files
+/// (`sha1` fields in libraries, assets, etc).
+#[must_use]
+pub fn get_sha1(bytes: &[u8]) -> String {
+    format!("{:x}", Sha1::digest(bytes))
+}
+
 const NETWORK_ERROR_MSG: &str = r"
 - Check your internet connection
 - Check if you are behind a firewall/proxy
@@ -279,6 +450,16 @@ pub enum RequestError {
     InvalidHeaderValue(#[from] InvalidHeaderValue),
     #[error("{0}")]
     Message(String),
+    #[error(
+        "Download Error (file is corrupted){NETWORK_ERROR_MSG}Url: {url}\nExpected sha1: {expected}\nGot sha1: {got}"
+    )]
+    HashMismatch {
+        url: String,
+        expected: String,
+        got: String,
+    },
+    #[error("{0}")]
+    Io(#[from] IoError),
 }
 
 impl RequestError {
@@ -294,6 +475,10 @@ impl RequestError {
             }
             RequestError::MiddlewareError(error) => format!("Download error (middleware): {error}"),
             RequestError::Message(msg) => msg.clone(),
+            RequestError::HashMismatch { url, .. } => {
+                format!("Download Error (file is corrupted)\nUrl: {url}")
+            }
+            RequestError::Io(error) => error.to_string(),
         }
     }
 }
@@ -454,9 +639,14 @@ pub async fn read_filenames_from_dir<P: AsRef<Path>>(dir: P) -> Result<Vec<DirIt
         parent: dir.to_owned(),
     })? {
         if let Some(name) = entry.file_name().to_str() {
+            let path = entry.path();
+            let is_symlink = tokio::fs::symlink_metadata(&path)
+                .await
+                .is_ok_and(|n| n.file_type().is_symlink());
             filenames.push(DirItem {
                 name: name.to_owned(),
-                is_file: entry.path().is_file(),
+                is_file: path.is_file(),
+                is_symlink,
             });
         }
     }
@@ -467,7 +657,13 @@ pub async fn read_filenames_from_dir<P: AsRef<Path>>(dir: P) -> Result<Vec<DirIt
 #[derive(Debug, Clone)]
 pub struct DirItem {
     pub name: String,
+    /// Whether this is a file (or a symlink to one).
+    ///
+    /// `false` for directories *and* broken symlinks.
     pub is_file: bool,
+    /// Whether this entry itself is a symlink
+    /// (regardless of what it points to).
+    pub is_symlink: bool,
 }
 
 /// Finds the first in the specified directory
@@ -637,6 +833,39 @@ pub async fn exists(p: impl AsRef<Path>) -> bool {
     tokio::fs::try_exists(p).await.is_ok_and(|n| n)
 }
 
+/// Writes `contents` to `path` without ever leaving
+/// a half-written file behind.
+///
+/// The data is first written to a temporary file next to `path`
+/// (`<name>.<n>.tmp`), which is then renamed over `path`.
+/// If the launcher crashes midway, `path` still has its old contents.
+///
+/// Each call uses its own temporary file, so overlapping
+/// writes to the same `path` don't interfere with each other.
+///
+/// # Errors
+/// - The temporary file couldn't be written to
+/// - It couldn't be renamed to `path`
+pub async fn atomic_write(path: &Path, contents: impl AsRef<[u8]>) -> Result<(), IoError> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let mut tmp_name = path.file_name().unwrap_or_default().to_owned();
+    tmp_name.push(format!(
+        ".{}.tmp",
+        COUNTER.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    ));
+    let tmp_path = path.with_file_name(tmp_name);
+
+    tokio::fs::write(&tmp_path, contents)
+        .await
+        .path(&tmp_path)?;
+    if let Err(err) = tokio::fs::rename(&tmp_path, path).await {
+        _ = tokio::fs::remove_file(&tmp_path).await;
+        return Err(err.path(path));
+    }
+    Ok(())
+}
+
 /// Extracts a `.tar.gz` file from a `&[u8]` buffer into the given directory.
 ///
 /// Does not create a top-level directory,
@@ -5,6 +5,15 @@ use serde::{Deserialize, Serialize};
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct JarMods {
     pub mods: Vec<JarMod>,
+    /// Whether to reuse the last built jar if the
+    /// original jar and enabled jarmods haven't changed
+    /// (default: `true`).
+    #[serde(default = "default_true")]
+    pub use_cache: bool,
+}
+
+fn default_true() -> bool {
+    true
 }
 
 impl JarMods {
@@ -16,7 +25,10 @@ impl JarMods {
             let file = serde_json::from_str(&file).json(file)?;
             Ok(file)
         } else {
-            let file = Self { mods: Vec::new() };
+            let file = Self {
+                mods: Vec::new(),
+                use_cache: true,
+            };
             let file_str = serde_json::to_string(&file).json_to()?;
             tokio::fs::write(&path, &file_str).await.path(&file_str)?;
             Ok(file)
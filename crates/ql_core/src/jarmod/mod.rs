@@ -19,12 +19,13 @@
 use std::path::{Path, PathBuf, StripPrefixError};
 
 use crate::{
-    Instance, IntoIoError, IoError, JsonError, JsonFileError,
-    file_utils::{extract_zip_archive, zip_directory_to_bytes},
+    Instance, IntoIoError, IntoJsonError, IoError, JsonError, JsonFileError,
+    file_utils::{extract_zip_archive, get_sha1, zip_directory_to_bytes},
     get_jar_path,
     json::{InstanceConfigJson, JsonOptifine, VersionDetails},
     pt,
 };
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 mod json;
@@ -97,20 +98,41 @@ pub async fn build(instance: &Instance) -> Result<PathBuf, JarModError> {
     let mut index = JarMods::read(instance).await?;
     index.expand(instance).await?;
 
+    let original_jar_bytes = tokio::fs::read(&original_jar).await.path(&original_jar)?;
+    let mut jars = Vec::new();
+    for jar in index.mods.iter().filter(|n| n.enabled) {
+        let path = jarmods_dir.join(&jar.filename);
+        let bytes = tokio::fs::read(&path).await.path(&path)?;
+        jars.push((jar.filename.as_str(), bytes));
+    }
+
+    let out_jar = instance_dir.join("build.jar");
+    let manifest_path = instance_dir.join("jarmod_manifest.json");
+    let manifest = BuildManifest {
+        base_jar_sha1: get_sha1(&original_jar_bytes),
+        mods: jars
+            .iter()
+            .map(|(name, bytes)| BuildManifestEntry {
+                name: (*name).to_owned(),
+                sha1: get_sha1(bytes),
+            })
+            .collect(),
+    };
+    if index.use_cache
+        && out_jar.is_file()
+        && BuildManifest::read(&manifest_path).await.as_ref() == Some(&manifest)
+    {
+        pt!("Jarmods unchanged, reusing build.jar");
+        return Ok(out_jar);
+    }
+
     let tmp_dir = jarmods_dir.join("tmp");
     tokio::fs::create_dir_all(&tmp_dir).await.path(&tmp_dir)?;
 
-    let original_jar_bytes = tokio::fs::read(&original_jar).await.path(&original_jar)?;
     extract_zip_archive(std::io::Cursor::new(original_jar_bytes), &tmp_dir, true).await?;
 
-    for jar in &index.mods {
-        if !jar.enabled {
-            continue;
-        }
-
-        pt!("{}", jar.filename);
-        let path = jarmods_dir.join(&jar.filename);
-        let bytes = tokio::fs::read(&path).await.path(&path)?;
+    for (name, bytes) in jars {
+        pt!("{name}");
         extract_zip_archive(std::io::Cursor::new(bytes), &tmp_dir, true).await?;
     }
 
@@ -122,14 +144,40 @@ pub async fn build(instance: &Instance) -> Result<PathBuf, JarModError> {
     let zip = zip_directory_to_bytes(&tmp_dir)
         .await
         .map_err(JarModError::ZipWriteError)?;
-    let out_jar = instance_dir.join("build.jar");
     tokio::fs::write(&out_jar, &zip).await.path(&out_jar)?;
 
     tokio::fs::remove_dir_all(&tmp_dir).await.path(&tmp_dir)?;
 
+    let manifest = serde_json::to_string(&manifest).json_to()?;
+    tokio::fs::write(&manifest_path, manifest)
+        .await
+        .path(&manifest_path)?;
+
     Ok(out_jar)
 }
 
+/// Stored in `jarmod_manifest.json` after building `build.jar`,
+/// to detect whether it needs to be rebuilt.
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+struct BuildManifest {
+    base_jar_sha1: String,
+    /// Enabled jarmods, in the order they were applied
+    mods: Vec<BuildManifestEntry>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq)]
+struct BuildManifestEntry {
+    name: String,
+    sha1: String,
+}
+
+impl BuildManifest {
+    async fn read(path: &Path) -> Option<Self> {
+        let file = tokio::fs::read_to_string(path).await.ok()?;
+        serde_json::from_str(&file).ok()
+    }
+}
+
 async fn get_original_jar(
     instance: &Instance,
     instance_dir: &Path,
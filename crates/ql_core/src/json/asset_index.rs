@@ -2,7 +2,7 @@ use std::{collections::HashMap, path::Path};
 
 use serde::{Deserialize, Serialize};
 
-use crate::{DownloadFileError, IntoIoError, RequestError, download, err};
+use crate::{DownloadFileError, IntoIoError, RequestError, download, err, file_utils::get_sha1};
 
 #[derive(Serialize, Deserialize)]
 pub struct AssetIndex {
@@ -19,6 +19,35 @@ pub struct AssetObject {
 }
 
 impl AssetObject {
+    /// Whether this asset has already been downloaded to
+    /// `objects_path` (shared by all instances).
+    ///
+    /// Normally only the file's existence and size are checked.
+    /// The SHA-1 hash is only checked if `verify_hash` is set
+    /// (eg: when repairing an instance) or if the size
+    /// doesn't match the one in the index.
+    pub async fn exists_and_valid(&self, objects_path: &Path, verify_hash: bool) -> bool {
+        let path = objects_path.join(&self.hash[0..2]).join(&self.hash);
+        let Ok(metadata) = tokio::fs::metadata(&path).await else {
+            return false;
+        };
+        let size_matches = self.size.is_none_or(|size| metadata.len() == size as u64);
+        if size_matches && !verify_hash {
+            return true;
+        }
+
+        let Ok(bytes) = tokio::fs::read(&path).await else {
+            return false;
+        };
+        // Some custom asset indexes don't use SHA-1 hashes as names
+        if self.hash.len() != 40 {
+            return true;
+        }
+        get_sha1(&bytes) == self.hash
+    }
+
+    /// Downloads this asset to `objects_path`,
+    /// overwriting any existing file.
     pub async fn download(&self, objects_path: &Path) -> Result<(), DownloadFileError> {
         const OBJECTS_URL: &str = "https://resources.download.minecraft.net";
 
@@ -30,18 +59,6 @@ impl AssetObject {
             .path(&obj_folder)?;
 
         let obj_file_path = obj_folder.join(&self.hash);
-        if let Ok(obj_file_metadata) = tokio::fs::metadata(&obj_file_path)
-            .await
-            .path(&obj_file_path)
-        {
-            if let Some(size) = self.size {
-                if obj_file_metadata.len() == size as u64 {
-                    return Ok(());
-                }
-            } else {
-                return Ok(());
-            }
-        }
 
         let url = self
             .url
@@ -8,11 +8,33 @@ use crate::json::version::LibraryRule;
 #[allow(non_snake_case)]
 pub struct FabricJSON {
     pub mainClassServer: Option<String>,
+    #[serde(default)]
     pub mainClass: String,
     pub arguments: Option<Arguments>,
     pub libraries: Vec<Library>,
 }
 
+impl FabricJSON {
+    /// The main class for launching the client,
+    /// or `None` if it's missing/empty.
+    #[must_use]
+    pub fn get_client_main_class(&self) -> Option<&str> {
+        Some(self.mainClass.trim()).filter(|n| !n.is_empty())
+    }
+
+    /// The main class for launching a server
+    /// (`mainClassServer`, falling back to `mainClass`),
+    /// or `None` if both are missing/empty.
+    #[must_use]
+    pub fn get_server_main_class(&self) -> Option<&str> {
+        self.mainClassServer
+            .as_deref()
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+            .or_else(|| self.get_client_main_class())
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct Arguments {
     pub jvm: Option<Vec<String>>,
@@ -78,5 +78,5 @@ pub struct JsonDetailsArtifact {
     pub path: String,
     pub url: String,
     sha1: String,
-    size: usize,
+    pub size: usize,
 }
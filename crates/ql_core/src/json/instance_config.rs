@@ -1,5 +1,6 @@
 use std::{
     collections::HashMap,
+    fmt::Debug,
     path::{Path, PathBuf},
     sync::Arc,
 };
@@ -21,7 +22,7 @@ use crate::{
 /// - Server: `QuantumLauncher/servers/<NAME>/config.json`
 ///
 /// See the documentation of each field for more information.
-#[derive(Serialize, Deserialize, Clone, Debug)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct InstanceConfigJson {
     /// Memory allocation in MB
     // Since: v0.1
@@ -97,12 +98,119 @@ pub struct InstanceConfigJson {
     /// An override for the main class when launching the game.
     /// Mainly only used for debugging purposes.
     pub main_class_override: Option<String>,
+    /// **macOS only**: Whether to pass `-XstartOnFirstThread` to Java.
+    ///
+    /// LWJGL 3 (1.13 and above) needs this flag, but it can
+    /// make LWJGL 2 (1.12.2 and below) freeze on startup.
+    ///
+    /// **Default: `None`** (decided based on version)
+    pub force_x_first_thread: Option<bool>,
+    /// Extra JAR files to add to the end of the classpath
+    /// (eg: a patching agent). Each entry is either an absolute path,
+    /// or a path relative to the instance directory.
+    ///
+    /// **Default: `None`**
+    pub classpath_extras: Option<Vec<String>>,
+    /// **Client only:** Custom location of the Forge/NeoForge
+    /// files, for instances where they were moved elsewhere.
+    /// Either an absolute path, or a path relative to
+    /// the instance directory.
+    ///
+    /// **Default: `None`** (`instance_dir/forge`)
+    pub forge_dir_override: Option<PathBuf>,
+    /// **Client only:** If set, the game is considered hung
+    /// and is killed when it prints nothing (to `stdout` or
+    /// `stderr`) for this many seconds.
+    ///
+    /// Off by default, as the game can legitimately stay
+    /// quiet for a long time (eg: paused, or in the main menu).
+    ///
+    /// **Default: `None`**
+    pub hang_timeout_secs: Option<u64>,
 
     #[serde(flatten)]
     _extra: HashMap<String, serde_json::Value>,
 }
 
+impl Debug for InstanceConfigJson {
+    #[allow(deprecated)]
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("InstanceConfigJson")
+            .field("ram_in_mb", &self.ram_in_mb)
+            .field("mod_type", &self.mod_type)
+            .field("mod_type_info", &self.mod_type_info)
+            .field("java_override_version", &self.java_override_version)
+            .field("java_override", &self.java_override)
+            .field("enable_logger", &self.enable_logger)
+            .field("java_args", &self.java_args.as_deref().map(redact_args))
+            .field("game_args", &self.game_args.as_deref().map(redact_args))
+            .field("omniarchive", &self.omniarchive)
+            .field("is_classic_server", &self.is_classic_server)
+            .field("is_server", &self.is_server)
+            .field("close_on_start", &self.close_on_start)
+            .field("global_settings", &self.global_settings)
+            .field("global_java_args_enable", &self.global_java_args_enable)
+            .field("pre_launch_prefix_mode", &self.pre_launch_prefix_mode)
+            .field("custom_jar", &self.custom_jar)
+            .field("version_info", &self.version_info)
+            .field("main_class_override", &self.main_class_override)
+            .field("force_x_first_thread", &self.force_x_first_thread)
+            .field("classpath_extras", &self.classpath_extras)
+            .field("forge_dir_override", &self.forge_dir_override)
+            .field("_extra", &self._extra)
+            .finish()
+    }
+}
+
+/// Hides arguments that look like they contain secrets
+/// (`token`, `secret`) from debug output, unless
+/// [`crate::flags::redact_sensitive_info`] is turned off.
+///
+/// Handles both `--accessToken=abc` and `--accessToken abc`
+/// (where the value is the next argument).
+fn redact_args(args: &[String]) -> Vec<&str> {
+    if !crate::flags::redact_sensitive_info() {
+        return args.iter().map(String::as_str).collect();
+    }
+    let mut redact_next = false;
+    args.iter()
+        .map(|arg| {
+            let is_value = std::mem::take(&mut redact_next);
+            let lower = arg.to_lowercase();
+            let is_sensitive = lower.contains("token") || lower.contains("secret");
+            if is_sensitive && arg.starts_with('-') && !arg.contains('=') {
+                // Keep the flag name, hide the value that follows
+                redact_next = true;
+                arg.as_str()
+            } else if is_value || is_sensitive {
+                "[REDACTED]"
+            } else {
+                arg.as_str()
+            }
+        })
+        .collect()
+}
+
 impl InstanceConfigJson {
+    /// Returns a human-readable name of the installed
+    /// mod loader, including the unofficial implementation
+    /// and version if known.
+    ///
+    /// For example: `Vanilla`, `Fabric 0.16.10`,
+    /// `Fabric (Legacy) 0.16.9`
+    #[must_use]
+    pub fn get_mod_loader_display_name(&self) -> String {
+        let info = self.mod_type_info.as_ref();
+        let name = info
+            .and_then(|n| n.backend_implementation.clone())
+            .unwrap_or_else(|| self.mod_type.to_string());
+
+        match info.and_then(|n| n.version.as_deref()) {
+            Some(version) if !self.mod_type.is_vanilla() => format!("{name} {version}"),
+            _ => name,
+        }
+    }
+
     #[must_use]
     pub fn new(kind: InstanceKind, is_classic_server: bool, version_info: VersionInfo) -> Self {
         #[allow(deprecated)]
@@ -128,10 +236,57 @@ impl InstanceConfigJson {
 
             version_info: Some(version_info),
             main_class_override: None,
+            force_x_first_thread: None,
+            classpath_extras: None,
+            forge_dir_override: None,
+            hang_timeout_secs: None,
             _extra: HashMap::new(),
         }
     }
 
+    /// Whether this is a server, not a client (default: `false`).
+    #[must_use]
+    pub fn is_server(&self) -> bool {
+        self.is_server.unwrap_or(false)
+    }
+
+    /// The `hang_timeout` to pass to
+    /// [`crate::LaunchedProcess::read_logs`], from
+    /// [`InstanceConfigJson::hang_timeout_secs`].
+    ///
+    /// Always `None` for servers, as they can
+    /// print nothing for hours.
+    #[must_use]
+    pub fn get_hang_timeout(&self) -> Option<std::time::Duration> {
+        if self.is_server() {
+            return None;
+        }
+        self.hang_timeout_secs.map(std::time::Duration::from_secs)
+    }
+
+    /// The directory Forge/NeoForge files are stored in,
+    /// taking [`InstanceConfigJson::forge_dir_override`] into account.
+    #[must_use]
+    pub fn get_forge_dir(&self, instance_dir: &Path) -> PathBuf {
+        match &self.forge_dir_override {
+            Some(dir) => instance_dir.join(dir),
+            None => instance_dir.join("forge"),
+        }
+    }
+
+    /// Resolves [`InstanceConfigJson::classpath_extras`] into full paths,
+    /// with relative paths being relative to `instance_dir`.
+    #[must_use]
+    pub fn get_class_path_extras(&self, instance_dir: &Path) -> Vec<PathBuf> {
+        self.classpath_extras
+            .iter()
+            .flatten()
+            .map(|n| n.trim())
+            .filter(|n| !n.is_empty())
+            .map(|n| instance_dir.join(n))
+            .collect()
+    }
+
     /// Returns a String containing the Java argument to
     /// allocate the configured amount of RAM.
     #[must_use]
@@ -220,13 +375,13 @@ impl InstanceConfigJson {
     /// Whether to combine with global prefixes, and how,
     /// depends on the instance's [`PreLaunchPrefixMode`].
     #[must_use]
-    pub fn build_launch_prefix(&mut self, global_prefix: &[String]) -> Vec<String> {
+    pub fn build_launch_prefix(&self, global_prefix: &[String]) -> Vec<String> {
         let mode = self.pre_launch_prefix_mode.unwrap_or_default();
 
         let mut instance_prefix: Vec<String> = self
-            .c_global_settings()
-            .pre_launch_prefix
-            .iter_mut()
+            .global_settings
+            .iter()
+            .filter_map(|n| n.pre_launch_prefix.as_ref())
             .flatten()
             .map(|n| n.trim().to_owned())
             .filter(|n| !n.is_empty())
@@ -356,6 +511,53 @@ pub struct GlobalSettings {
     _extra: HashMap<String, serde_json::Value>,
 }
 
+impl GlobalSettings {
+    /// Applies an instance's overrides on top of these
+    /// global defaults, giving the settings to actually launch with.
+    ///
+    /// `global_java_args` are the launcher-wide Java arguments
+    /// (only used if the instance has them enabled).
+    #[must_use]
+    pub fn merge_with_instance(
+        &self,
+        instance: &InstanceConfigJson,
+        global_java_args: &[String],
+    ) -> EffectiveSettings {
+        let (window_width, window_height) = instance.get_window_size(Some(self));
+        EffectiveSettings {
+            ram_in_mb: instance.ram_in_mb,
+            java_args: instance.get_java_args(global_java_args),
+            window_width,
+            window_height,
+            pre_launch_prefix: instance
+                .build_launch_prefix(self.pre_launch_prefix.as_deref().unwrap_or_default()),
+        }
+    }
+}
+
+/// The result of [`GlobalSettings::merge_with_instance`]:
+/// instance settings with the global defaults filled in.
+#[derive(Debug, Clone, Default)]
+pub struct EffectiveSettings {
+    pub ram_in_mb: usize,
+    /// Java arguments, without the RAM argument.
+    pub java_args: Vec<String>,
+    /// `None` means Minecraft's own default.
+    pub window_width: Option<u32>,
+    /// `None` means Minecraft's own default.
+    pub window_height: Option<u32>,
+    /// Commands to put before the Java command (empty if none).
+    pub pre_launch_prefix: Vec<String>,
+}
+
+impl EffectiveSettings {
+    /// Returns the Java argument to allocate the configured amount of RAM.
+    #[must_use]
+    pub fn get_ram_argument(&self) -> String {
+        format!("-Xmx{}M", self.ram_in_mb)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VersionInfo {
     is_special_lwjgl3: bool,
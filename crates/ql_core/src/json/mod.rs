@@ -6,14 +6,16 @@ pub mod asset_index;
 pub mod instance_config;
 pub mod manifest;
 pub mod version;
+pub mod version_constants;
 
 pub use fabric::FabricJSON;
 pub use optifine::{JsonOptifine, OptifineArguments, OptifineLibrary};
 
 pub use asset_index::AssetIndex;
-pub use instance_config::{GlobalSettings, InstanceConfigJson};
+pub use instance_config::{EffectiveSettings, GlobalSettings, InstanceConfigJson};
 pub use manifest::Manifest;
-pub use version::{
-    V_1_5_2, V_1_12_2, V_LAST_TEXTUREPACK, V_OFFICIAL_FABRIC_SUPPORT, V_PAULSCODE_LAST,
-    V_PRECLASSIC_LAST, VersionDetails,
+pub use version::VersionDetails;
+pub use version_constants::{
+    V_1_1, V_1_5_2, V_1_8, V_1_12_2, V_1_20_2, V_21W19A, V_LAST_TEXTUREPACK,
+    V_OFFICIAL_FABRIC_SUPPORT, V_PAULSCODE_LAST, V_PRECLASSIC_LAST,
 };
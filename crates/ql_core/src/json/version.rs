@@ -5,20 +5,15 @@ use chrono::DateTime;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use crate::{Instance, IntoIoError, IntoJsonError, JsonFileError, OS_NAME, err, pt};
+use crate::{
+    Instance, IntoIoError, IntoJsonError, JsonFileError, ListEntryKind, Loader, REGEX_SNAPSHOT,
+    err, pt,
+};
 
 #[allow(clippy::wildcard_imports)] // items may vary based on platform
 use crate::constants::*;
 
-pub const V_PRECLASSIC_LAST: &str = "2009-05-16T11:48:00+00:00";
-pub const V_OFFICIAL_FABRIC_SUPPORT: &str = "2018-10-24T10:52:16+00:00";
-pub const V_1_5_2: &str = "2013-04-25T15:45:00+00:00";
-pub const V_1_12_2: &str = "2017-09-18T08:39:46+00:00";
-pub const V_PAULSCODE_LAST: &str = "2019-03-14T14:26:23+00:00";
-/// Minecraft 13w23b release date (1.6.1 snapshot)
-///
-/// Last version with Texture Packs instead of Resource Packs
-pub const V_LAST_TEXTUREPACK: &str = "2013-06-08T00:32:01+00:00";
+pub use super::version_constants::*;
 
 #[allow(non_snake_case)]
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -62,6 +57,18 @@ pub struct VersionDetails {
     /// Type of version, such as alpha, beta or release.
     pub r#type: String,
 
+    /// The `uid`s of all the patches applied by [`Self::apply_tweaks`],
+    /// such as `net.fabricmc.intermediary` or `org.lwjgl3`.
+    ///
+    /// These come from the `patches/*.json` files of instances
+    /// imported from MultiMC/Prism Launcher. A patch's `uid` is the
+    /// `group.artifact` (with the `:` replaced by `.`) of the library
+    /// it provides. Since the patch already puts its own version of
+    /// that library on the classpath, the copy listed by the
+    /// loader (eg: Fabric's JSON) must be skipped, otherwise
+    /// both versions would end up on the classpath and conflict.
+    ///
+    /// Only exists at runtime, never saved to `details.json`.
     #[serde(skip)]
     pub q_patch_overrides: Vec<String>,
 }
@@ -169,40 +176,60 @@ impl VersionDetails {
         self.is_before_or_eq(V_LAST_TEXTUREPACK)
     }
 
+    /// Whether this version was released on or before `release_time`
+    /// (one of the `V_*` constants, or any RFC 3339 date).
+    ///
+    /// If this version's own `releaseTime` can't be parsed
+    /// (eg: some modded JSONs), the version id is looked up in
+    /// [`KNOWN_RELEASE_TIMES`] instead. If that fails too,
+    /// this returns `false`.
     #[must_use]
     pub fn is_before_or_eq(&self, release_time: &str) -> bool {
-        match (
-            DateTime::parse_from_rfc3339(&self.releaseTime),
-            DateTime::parse_from_rfc3339(release_time),
-        ) {
-            (Ok(dt), Ok(rt)) => dt <= rt,
-            (Err(err), Ok(_)) | (Ok(_), Err(err)) => {
-                err!("Could not parse date/time: {err}");
-                false
-            }
-            (Err(err1), Err(err2)) => {
-                err!("Could not parse date/time\n(1): {err1}\n(2): {err2}");
-                false
-            }
-        }
+        self.compare_release_time(release_time)
+            .is_some_and(std::cmp::Ordering::is_le)
     }
 
+    /// Whether this version needs a modern OpenGL (3.2+ core profile)
+    /// context, ie. 1.17 and above.
+    ///
+    /// Some drivers (eg: Mesa on Raspberry Pi) don't advertise
+    /// this by default even if they support it.
+    #[must_use]
+    pub fn requires_opengl_3_3(&self) -> bool {
+        self.is_after_or_eq(V_21W19A)
+    }
+
+    /// Whether this version was released on or after `release_time`.
+    ///
+    /// See [`Self::is_before_or_eq`] for the fallback behavior.
     #[must_use]
     pub fn is_after_or_eq(&self, release_time: &str) -> bool {
-        match (
-            DateTime::parse_from_rfc3339(&self.releaseTime),
-            DateTime::parse_from_rfc3339(release_time),
-        ) {
-            (Ok(dt), Ok(rt)) => dt >= rt,
-            (Err(err), Ok(_)) | (Ok(_), Err(err)) => {
+        self.compare_release_time(release_time)
+            .is_some_and(std::cmp::Ordering::is_ge)
+    }
+
+    fn compare_release_time(&self, release_time: &str) -> Option<std::cmp::Ordering> {
+        let other = match DateTime::parse_from_rfc3339(release_time) {
+            Ok(n) => n,
+            Err(err) => {
                 err!("Could not parse date/time: {err}");
-                false
+                return None;
             }
-            (Err(err1), Err(err2)) => {
-                err!("Could not parse date/time\n(1): {err1}\n(2): {err2}");
-                false
+        };
+        let this = match DateTime::parse_from_rfc3339(&self.releaseTime) {
+            Ok(n) => n,
+            Err(err) => {
+                let known = KNOWN_RELEASE_TIMES
+                    .iter()
+                    .find(|(id, _)| *id == self.get_id())
+                    .and_then(|(_, time)| DateTime::parse_from_rfc3339(time).ok());
+                if known.is_none() {
+                    err!("Could not parse date/time: {err}");
+                }
+                known?
             }
-        }
+        };
+        Some(this.cmp(&other))
     }
 
     #[must_use]
@@ -214,6 +241,282 @@ impl VersionDetails {
     pub fn get_id(&self) -> &str {
         self.id.strip_suffix("-lwjgl3").unwrap_or(&self.id)
     }
+
+    /// The major Java version this version asks for
+    /// (`javaVersion.majorVersion`).
+    ///
+    /// Old versions (before 1.17) don't have a `javaVersion` field,
+    /// so this defaults to `8`.
+    #[must_use]
+    pub fn get_java_major_version(&self) -> u8 {
+        self.javaVersion
+            .as_ref()
+            .map_or(8, |n| u8::try_from(n.majorVersion).unwrap_or(u8::MAX))
+    }
+
+    /// The natives classifier this version uses for the current
+    /// platform (eg: `natives-linux`, `natives-osx`,
+    /// `natives-windows`, `natives-linux-arm64`).
+    ///
+    /// Older versions list it in the `natives` field of libraries,
+    /// while newer ones (LWJGL 3.3+) have separate libraries
+    /// with the classifier at the end of the name.
+    ///
+    /// Returns `None` if no library has natives for this platform.
+    #[must_use]
+    pub fn get_natives_classifier(&self) -> Option<String> {
+        let libraries = self.libraries.iter().filter(|n| n.is_allowed());
+        if let Some(name) = libraries.clone().find_map(Library::get_natives_name) {
+            return Some(name);
+        }
+
+        let classifiers: Vec<&str> = libraries
+            .filter_map(|n| n.name.as_deref()?.split(':').nth(3))
+            .collect();
+        OS_NAMES.iter().find_map(|os| {
+            cfg_if!(
+                if #[cfg(any(
+                    target_arch = "aarch64",
+                    target_arch = "arm",
+                    target_arch = "x86",
+                    feature = "simulate_linux_arm64",
+                    feature = "simulate_macos_arm64",
+                    feature = "simulate_linux_arm32",
+                ))] {
+                    // Fall back to the generic natives if there
+                    // aren't any for this architecture
+                    let candidates = [format!("natives-{os}-{ARCH}"), format!("natives-{os}")];
+                } else {
+                    let candidates = [format!("natives-{os}")];
+                }
+            );
+            candidates
+                .into_iter()
+                .find(|n| classifiers.contains(&n.as_str()))
+        })
+    }
+
+    /// The id of the asset index used by this version.
+    ///
+    /// This can differ from [`VersionDetails::get_id`],
+    /// as old versions share asset indexes (eg: `pre-1.6`).
+    #[must_use]
+    pub fn get_id_for_asset_index(&self) -> &str {
+        &self.assetIndex.id
+    }
+
+    /// The Java class containing the game's `main` function.
+    ///
+    /// Prefer this over accessing `mainClass` directly.
+    #[must_use]
+    pub fn get_main_class(&self) -> &str {
+        &self.mainClass
+    }
+
+    /// Overrides the main class (used by tweaks).
+    pub fn set_main_class(&mut self, class: String) {
+        self.mainClass = class;
+    }
+
+    /// Classifies this version (release, snapshot, beta, ...)
+    /// based on its id and `type` field.
+    #[must_use]
+    pub fn kind(&self) -> ListEntryKind {
+        ListEntryKind::calculate(self.get_id(), &self.r#type)
+    }
+
+    /// Whether this is a full release (`1.x`).
+    #[must_use]
+    pub fn is_release(&self) -> bool {
+        self.kind() == ListEntryKind::Release
+    }
+
+    /// Whether this is a snapshot, like `24w14a`
+    /// or `1.21-pre1`.
+    #[must_use]
+    pub fn is_snapshot(&self) -> bool {
+        self.kind() == ListEntryKind::Snapshot || REGEX_SNAPSHOT.is_match(self.get_id())
+    }
+
+    /// Whether this is one of the April Fools versions,
+    /// like `20w14infinite`.
+    #[must_use]
+    pub fn is_april_fools(&self) -> bool {
+        self.kind() == ListEntryKind::AprilFools
+    }
+
+    /// Whether this is a Classic version (`c0.*`).
+    #[must_use]
+    pub fn is_classic(&self) -> bool {
+        self.kind() == ListEntryKind::Classic
+    }
+
+    /// Whether this is a Beta version (`b1.*`).
+    #[must_use]
+    pub fn is_beta(&self) -> bool {
+        self.kind() == ListEntryKind::Beta
+    }
+
+    /// Whether Forge can be installed for this version.
+    ///
+    /// Forge is only available for Minecraft 1.1 and above,
+    /// so this is `false` for 1.0 and everything before it
+    /// (Classic, Indev, Infdev, Alpha, Beta).
+    #[must_use]
+    pub fn supports_forge(&self) -> bool {
+        !(self.kind().is_old() || self.get_id() == "1.0")
+    }
+
+    /// Roughly guesses which loaders can be installed
+    /// for this version, based on its release date.
+    ///
+    /// This is only a heuristic for showing to the user:
+    /// - Fabric, Quilt: 1.14 snapshots and above (older versions
+    ///   may still work through Legacy Fabric or OrnitheMC)
+    /// - Forge: 1.1 and above (see [`VersionDetails::supports_forge`])
+    /// - NeoForge: 1.20.2 and above
+    /// - OptiFine: 1.2 and above
+    /// - Paper: 1.8 and above (**servers only**,
+    ///   so filter it out for clients)
+    #[must_use]
+    pub fn compatible_loaders(&self) -> Vec<Loader> {
+        let mut loaders = Vec::new();
+        if self.is_after_or_eq(V_OFFICIAL_FABRIC_SUPPORT) {
+            loaders.push(Loader::Fabric);
+            loaders.push(Loader::Quilt);
+        }
+        if self.supports_forge() {
+            loaders.push(Loader::Forge);
+        }
+        if self.is_after_or_eq(V_1_20_2) {
+            loaders.push(Loader::NeoForge);
+        }
+        if !self.is_before_or_eq(V_1_1) {
+            loaders.push(Loader::OptiFine);
+        }
+        if self.is_after_or_eq(V_1_8) {
+            loaders.push(Loader::Paper);
+        }
+        loaders
+    }
+
+    /// Returns the URL of the log4j XML config file
+    /// (`logging.client.file.url`), if any.
+    ///
+    /// For example:
+    /// `https://piston-data.mojang.com/v1/objects/<sha1>/client-1.12.xml`
+    ///
+    /// Old versions (before 1.7) don't have this.
+    #[must_use]
+    pub fn get_logging_config_url(&self) -> Option<&str> {
+        self.logging.as_ref().map(|n| n.client.file.url.as_str())
+    }
+
+    /// Roughly estimates how many bytes are needed to
+    /// download this version (client jar, libraries, and
+    /// optionally assets).
+    ///
+    /// This is a lower bound: natives and extracted
+    /// files aren't counted.
+    #[must_use]
+    pub fn estimate_download_size(&self, include_assets: bool) -> u64 {
+        let libraries: u64 = self
+            .libraries
+            .iter()
+            .filter(|n| n.is_allowed())
+            .filter_map(Library::get_artifact)
+            .filter_map(|n| n.size.as_u64())
+            .sum();
+        let assets = if include_assets {
+            self.assetIndex.totalSize as u64
+        } else {
+            0
+        };
+        self.downloads.client.size as u64 + libraries + assets
+    }
+
+    /// Returns the SHA-1 hash of the log4j XML config file
+    /// (`logging.client.file.sha1`), as a lowercase hex string.
+    #[must_use]
+    pub fn get_logging_config_sha1(&self) -> Option<&str> {
+        self.logging.as_ref().map(|n| n.client.file.sha1.as_str())
+    }
+
+    /// Returns the JVM arguments from `arguments.jvm`
+    /// that apply to the current platform.
+    ///
+    /// Entries conditioned on `rules` (OS name, arch, features)
+    /// are evaluated and only included if allowed. Placeholders
+    /// like `${natives_directory}` are left as-is.
+    ///
+    /// Returns an empty list for old versions
+    /// (1.12.2 and below) that only have `minecraftArguments`.
+    #[must_use]
+    pub fn get_arguments_jvm(&self) -> Vec<String> {
+        let Some(arguments) = &self.arguments else {
+            return Vec::new();
+        };
+        let mut out = Vec::new();
+        for arg in &arguments.jvm {
+            match arg {
+                Value::String(n) => out.push(n.clone()),
+                Value::Object(obj) => {
+                    let allowed = obj
+                        .get("rules")
+                        .and_then(Value::as_array)
+                        .is_none_or(|rules| argument_rules_allow(rules));
+                    if !allowed {
+                        continue;
+                    }
+                    match obj.get("value") {
+                        Some(Value::String(n)) => out.push(n.clone()),
+                        Some(Value::Array(values)) => out.extend(
+                            values
+                                .iter()
+                                .filter_map(Value::as_str)
+                                .map(ToOwned::to_owned),
+                        ),
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+        out
+    }
+}
+
+/// Evaluates the `rules` of a conditional argument in
+/// `arguments.game`/`arguments.jvm` for the current platform.
+///
+/// Rules requiring `features` (like `is_demo_user`) never match,
+/// as the launcher handles those by itself.
+fn argument_rules_allow(rules: &[Value]) -> bool {
+    let mut allowed = false;
+    for rule in rules {
+        if rule.get("features").is_some() {
+            continue;
+        }
+        if let Some(os) = rule.get("os") {
+            if os
+                .get("name")
+                .and_then(Value::as_str)
+                .is_some_and(|n| n != get_mojang_os_name())
+            {
+                continue;
+            }
+            // Mojang uses `x86` to mean any 32-bit system
+            if os
+                .get("arch")
+                .and_then(Value::as_str)
+                .is_some_and(|n| n != "x86" || cfg!(not(target_pointer_width = "32")))
+            {
+                continue;
+            }
+        }
+        allowed = rule.get("action").and_then(Value::as_str) == Some("allow");
+    }
+    allowed
 }
 
 impl Default for VersionDetails {
@@ -295,7 +598,8 @@ pub struct Download {
 #[allow(non_snake_case)]
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct JavaVersionJson {
-    component: String,
+    /// Mojang's Java runtime ID, like `java-runtime-delta`.
+    pub component: String,
     pub majorVersion: usize,
 }
 
@@ -349,9 +653,58 @@ impl Library {
         }
     }
 
+    /// Returns the natives classifier name for the current
+    /// platform (eg: `natives-linux`, `natives-osx`, `natives-windows`),
+    /// as listed in the `natives` field.
+    ///
+    /// `${arch}` (eg: in `natives-windows-${arch}`)
+    /// is replaced with `64` or `32`.
+    #[must_use]
+    pub fn get_natives_name(&self) -> Option<String> {
+        let natives = self.natives.as_ref()?;
+        cfg_if!(
+            if #[cfg(any(
+                target_arch = "aarch64",
+                target_arch = "arm",
+                target_arch = "x86",
+                feature = "simulate_linux_arm64",
+                feature = "simulate_macos_arm64",
+                feature = "simulate_linux_arm32",
+            ))] {
+                let name = natives.get(&format!("{}-{ARCH}", get_mojang_os_name()));
+            } else {
+                let name = natives.get(get_mojang_os_name());
+            }
+        );
+        let arch = if cfg!(target_pointer_width = "64") {
+            "64"
+        } else {
+            "32"
+        };
+        name.map(|n| n.replace("${arch}", arch))
+    }
+
+    /// Returns the path (relative to the `libraries` dir) of the
+    /// natives jar for the current platform, from `downloads.classifiers`.
+    ///
+    /// Returns `None` if this library has no natives for
+    /// the current platform.
+    #[must_use]
+    pub fn get_natives_path(&self) -> Option<String> {
+        let name = self.get_natives_name()?;
+        self.downloads
+            .as_ref()?
+            .classifiers
+            .as_ref()?
+            .get(&name)?
+            .path
+            .clone()
+    }
+
     #[must_use]
     pub fn is_allowed(&self) -> bool {
         let mut allowed: bool = true;
+        let os_name = get_mojang_os_name();
 
         if let Some(ref rules) = self.rules {
             allowed = false;
@@ -367,16 +720,16 @@ impl Library {
                             feature = "simulate_macos_arm64",
                             feature = "simulate_linux_arm32",
                         ))] {
-                            if os.name == format!("{OS_NAME}-{ARCH}") {
+                            if os.name == format!("{os_name}-{ARCH}") {
                                 allowed = rule.action == "allow";
                             }
                             if let Some(libname) = &self.name {
-                                if os.name == OS_NAME && libname.contains(ARCH) {
+                                if os.name == os_name && libname.contains(ARCH) {
                                     allowed = rule.action == "allow";
                                 }
                             }
                         } else {
-                            if os.name == OS_NAME {
+                            if os.name == os_name {
                                 allowed = rule.action == "allow";
                             }
                         }
@@ -386,7 +739,7 @@ impl Library {
                         all(target_os = "macos", target_arch = "aarch64"),
                         feature = "simulate_macos_arm64"
                     ))]
-                    if os.name == OS_NAME
+                    if os.name == os_name
                         && self.name.as_ref().is_some_and(|n| {
                             n.contains("natives-macos-arm64")
                                 || n == "ca.weblite:java-objc-bridge:1.1"
@@ -501,8 +854,9 @@ impl Debug for LibraryDownloads {
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct LibraryClassifier {
-    // pub path: Option<String>,
-    sha1: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    pub sha1: String,
     size: serde_json::Number,
     pub url: String,
 }
@@ -538,7 +892,7 @@ impl Debug for LibraryRuleOS {
 #[derive(Serialize, Deserialize, Clone)]
 pub struct LibraryDownloadArtifact {
     path: Option<String>,
-    sha1: String,
+    pub sha1: String,
     size: serde_json::Number,
     pub url: String,
 }
@@ -595,7 +949,100 @@ pub struct LoggingClient {
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct LoggingClientFile {
     pub id: String,
-    sha1: String,
+    pub sha1: String,
     size: usize,
     pub url: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments_jvm_rules() {
+        let arguments: Arguments = serde_json::from_value(serde_json::json!({
+            "game": [],
+            "jvm": [
+                { "rules": [{ "action": "allow", "os": { "name": get_mojang_os_name() } }],
+                  "value": ["-Dcurrent.os=true", "-Dcurrent.os.2=true"] },
+                { "rules": [{ "action": "allow", "os": { "name": "not-an-os" } }],
+                  "value": "-Dother.os=true" },
+                { "rules": [{ "action": "allow", "features": { "is_demo_user": true } }],
+                  "value": "--demo" },
+                "-Djava.library.path=${natives_directory}",
+                "-cp",
+                "${classpath}"
+            ]
+        }))
+        .unwrap();
+        let details = VersionDetails {
+            arguments: Some(arguments),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            details.get_arguments_jvm(),
+            [
+                "-Dcurrent.os=true",
+                "-Dcurrent.os.2=true",
+                "-Djava.library.path=${natives_directory}",
+                "-cp",
+                "${classpath}",
+            ]
+        );
+        assert!(VersionDetails::default().get_arguments_jvm().is_empty());
+    }
+
+    #[test]
+    fn natives_classifier_from_library_name() {
+        let classifier = format!("natives-{}", OS_NAMES[0]);
+        let libraries: Vec<Library> = serde_json::from_value(serde_json::json!([
+            { "name": "org.lwjgl:lwjgl:3.3.3" },
+            { "name": "org.lwjgl:lwjgl:3.3.3:natives-not-an-os" },
+            { "name": format!("org.lwjgl:lwjgl:3.3.3:{classifier}") },
+        ]))
+        .unwrap();
+        let details = VersionDetails {
+            libraries,
+            ..Default::default()
+        };
+        assert_eq!(details.get_natives_classifier(), Some(classifier));
+        assert_eq!(VersionDetails::default().get_natives_classifier(), None);
+    }
+
+    #[test]
+    fn natives_name_replaces_arch() {
+        let library: Library = serde_json::from_value(serde_json::json!({
+            "name": "org.lwjgl.lwjgl:lwjgl-platform:2.9.4",
+            "natives": {
+                "linux": "natives-linux-${arch}",
+                "osx": "natives-osx-${arch}",
+                "windows": "natives-windows-${arch}",
+            },
+        }))
+        .unwrap();
+        if let Some(name) = library.get_natives_name() {
+            assert!(!name.contains("${arch}"));
+        }
+    }
+
+    #[test]
+    fn release_time_fallback_to_version_id() {
+        let details = VersionDetails {
+            id: "1.12.2".to_owned(),
+            releaseTime: "not a date".to_owned(),
+            ..Default::default()
+        };
+        assert!(details.is_before_or_eq(V_1_12_2));
+        assert!(details.is_after_or_eq(V_1_8));
+        assert!(!details.is_before_or_eq(V_1_8));
+
+        let unknown = VersionDetails {
+            id: "some-modded-version".to_owned(),
+            releaseTime: "not a date".to_owned(),
+            ..Default::default()
+        };
+        assert!(!unknown.is_before_or_eq(V_1_20_2));
+        assert!(!unknown.is_after_or_eq(V_1_1));
+    }
+}
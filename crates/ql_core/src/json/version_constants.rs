@@ -0,0 +1,92 @@
+//! Release dates of versions that mark some boundary
+//! in the game's history (launch arguments, libraries, features, etc.)
+//!
+//! These are meant to be used with [`crate::json::VersionDetails::is_before_or_eq`]
+//! and [`crate::json::VersionDetails::is_after_or_eq`].
+
+/// Minecraft rd-161348 release date (pre-classic)
+///
+/// Last pre-classic version, these use a different main class
+/// (`com.mojang.rubydung.RubyDung`).
+pub const V_PRECLASSIC_LAST: &str = "2009-05-16T11:48:00+00:00";
+/// Minecraft 1.1 release date
+pub const V_1_1: &str = "2012-01-11T22:00:00+00:00";
+/// Minecraft 1.5.2 release date
+///
+/// Last "legacy" version, before the 1.6 launcher overhaul
+/// (different arguments, assets layout and main class).
+pub const V_1_5_2: &str = "2013-04-25T15:45:00+00:00";
+/// Minecraft 13w23b release date (1.6.1 snapshot)
+///
+/// Last version with Texture Packs instead of Resource Packs
+pub const V_LAST_TEXTUREPACK: &str = "2013-06-08T00:32:01+00:00";
+/// Minecraft 1.8 release date
+pub const V_1_8: &str = "2014-09-02T08:24:35+00:00";
+/// Minecraft 1.12.2 release date
+///
+/// Last release using LWJGL 2, 1.13 switched to LWJGL 3.
+pub const V_1_12_2: &str = "2017-09-18T08:39:46+00:00";
+/// Minecraft 18w43b release date (1.14 snapshot)
+///
+/// First version officially supported by Fabric.
+pub const V_OFFICIAL_FABRIC_SUPPORT: &str = "2018-10-24T10:52:16+00:00";
+/// Minecraft 19w11b release date (1.14 snapshot)
+///
+/// Last version using the paulscode sound system.
+pub const V_PAULSCODE_LAST: &str = "2019-03-14T14:26:23+00:00";
+/// Minecraft 21w19a release date (1.17 snapshot)
+///
+/// Around here the game started needing OpenGL 3.2+ (core profile)
+pub const V_21W19A: &str = "2021-05-12T11:19:15+00:00";
+/// Minecraft 1.20.2 release date
+pub const V_1_20_2: &str = "2023-09-20T09:02:57+00:00";
+
+/// Every boundary constant in this module,
+/// as `(name, release time)`, oldest first.
+///
+/// Useful for generating documentation.
+pub const ALL_BOUNDARIES: &[(&str, &str)] = &[
+    ("V_PRECLASSIC_LAST", V_PRECLASSIC_LAST),
+    ("V_1_1", V_1_1),
+    ("V_1_5_2", V_1_5_2),
+    ("V_LAST_TEXTUREPACK", V_LAST_TEXTUREPACK),
+    ("V_1_8", V_1_8),
+    ("V_1_12_2", V_1_12_2),
+    ("V_OFFICIAL_FABRIC_SUPPORT", V_OFFICIAL_FABRIC_SUPPORT),
+    ("V_PAULSCODE_LAST", V_PAULSCODE_LAST),
+    ("V_21W19A", V_21W19A),
+    ("V_1_20_2", V_1_20_2),
+];
+
+/// Release times of the versions that have a `V_*` constant,
+/// keyed by version id.
+///
+/// Used as a fallback by [`crate::json::VersionDetails::is_before_or_eq`]
+/// and [`crate::json::VersionDetails::is_after_or_eq`] when a version's
+/// own `releaseTime` isn't a valid date.
+pub const KNOWN_RELEASE_TIMES: &[(&str, &str)] = &[
+    ("1.1", V_1_1),
+    ("1.5.2", V_1_5_2),
+    ("1.8", V_1_8),
+    ("1.12.2", V_1_12_2),
+    ("21w19a", V_21W19A),
+    ("1.20.2", V_1_20_2),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::DateTime;
+
+    #[test]
+    fn boundaries_are_valid_and_sorted() {
+        let times: Vec<_> = ALL_BOUNDARIES
+            .iter()
+            .map(|(name, time)| {
+                DateTime::parse_from_rfc3339(time)
+                    .unwrap_or_else(|err| panic!("{name} is invalid: {err}"))
+            })
+            .collect();
+        assert!(times.is_sorted());
+    }
+}
@@ -16,7 +16,7 @@
 
 use crate::{
     json::manifest::Version,
-    read_log::{Diagnostic, LogLine, ReadError, read_logs},
+    read_log::{Diagnostic, LogLine, LogLineKind, ReadError, read_logs},
 };
 use futures::StreamExt;
 use json::VersionDetails;
@@ -28,7 +28,11 @@ use std::{
     future::Future,
     path::{Path, PathBuf},
     process::ExitStatus,
-    sync::{Arc, LazyLock, mpsc::Sender},
+    sync::{
+        Arc, LazyLock,
+        atomic::{AtomicBool, Ordering},
+        mpsc::Sender,
+    },
 };
 use tokio::process::Child;
 
@@ -55,7 +59,7 @@ pub use error::{
 };
 pub use file_utils::{LAUNCHER_CACHE_DIR, LAUNCHER_DIR, RequestError};
 pub use print::{LOGGER, LogType, LoggingState, logger_finish};
-pub use progress::{DownloadProgress, GenericProgress, Progress};
+pub use progress::{DownloadProgress, GenericProgress, MultiProgress, Progress, ProgressId};
 pub use request::download;
 pub use structs::{JavaVersion, Loader};
 
@@ -321,6 +325,19 @@ impl Instance {
         &self.name
     }
 
+    /// Whether this instance exists on disk.
+    ///
+    /// Prefer [`Instance::exists_async`] in async code.
+    #[must_use]
+    pub fn exists(&self) -> bool {
+        self.get_instance_path().exists()
+    }
+
+    /// Whether this instance exists on disk.
+    pub async fn exists_async(&self) -> bool {
+        file_utils::exists(self.get_instance_path()).await
+    }
+
     #[must_use]
     pub const fn is_server(&self) -> bool {
         self.kind.is_server()
@@ -440,6 +457,21 @@ impl ListEntryKind {
         set.remove(&Self::Special);
         set
     }
+
+    /// Whether this is from before Minecraft 1.0
+    /// (Pre-classic, Classic, Indev, Infdev, Alpha, Beta).
+    #[must_use]
+    pub const fn is_old(self) -> bool {
+        matches!(
+            self,
+            ListEntryKind::Preclassic
+                | ListEntryKind::Classic
+                | ListEntryKind::Indev
+                | ListEntryKind::Infdev
+                | ListEntryKind::Alpha
+                | ListEntryKind::Beta
+        )
+    }
 }
 
 impl ListEntryKind {
@@ -651,11 +683,23 @@ pub struct LaunchedProcess {
     /// - Downloaded and extracted from zip
     /// - Don't have a stop command (?), need to be killed
     pub is_classic_server: bool,
+    /// Set (through [`LaunchedProcess::mark_killed_by_user`]) when
+    /// the user stops the game, so that it isn't reported as a crash.
+    pub killed_by_user: Arc<AtomicBool>,
 }
 
 type ReadLogOut = Result<(ExitStatus, Instance, Option<Diagnostic>), ReadError>;
 
 impl LaunchedProcess {
+    /// Marks the process as stopped by the user (eg: "Kill" button),
+    /// so [`LaunchedProcess::read_logs`] returns [`ReadError::ProcessKilled`]
+    /// instead of treating the exit as a crash.
+    ///
+    /// Call this *before* killing the process.
+    pub fn mark_killed_by_user(&self) {
+        self.killed_by_user.store(true, Ordering::SeqCst);
+    }
+
     /// Reads log output from the game process.
     ///
     /// Runs until the process exits, then returns exit status
@@ -666,6 +710,12 @@ impl LaunchedProcess {
     ///   Leave blank if not needed
     /// - `sender`: Sender to send [`LogLine`]s to
     ///   (pretty printed in terminal if not present)
+    /// - `hang_timeout`: If the game prints nothing (to `stdout`
+    ///   or `stderr`) for this long, it's considered hung and is killed
+    ///   (returning [`ReadError::HangTimeout`]).
+    ///   Note: the game can legitimately go quiet for a while
+    ///   (eg: sitting in the main menu), so this should be opt-in
+    ///   (see [`InstanceConfigJson::get_hang_timeout`]).
     ///
     /// # Errors
     /// - `details.json` couldn't be read or parsed into JSON
@@ -677,8 +727,19 @@ impl LaunchedProcess {
         &self,
         censors: Vec<String>,
         sender: Option<Sender<LogLine>>,
+        hang_timeout: Option<std::time::Duration>,
     ) -> Option<ReadLogOut> {
-        Some(read_logs(self.child.clone(), sender, self.instance.clone(), censors).await)
+        Some(
+            read_logs(
+                self.child.clone(),
+                sender,
+                self.instance.clone(),
+                censors,
+                hang_timeout,
+                self.killed_by_user.clone(),
+            )
+            .await,
+        )
     }
 }
 
@@ -691,3 +752,63 @@ pub fn sanitize_instance_name(mut name: String) -> String {
     name.retain(|c| !disallowed.contains(&c));
     name.trim().to_owned()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[tokio::test]
+    async fn do_jobs_with_limit_enforces_limit() {
+        const LIMIT: usize = 10;
+        let running = Arc::new(AtomicUsize::new(0));
+        let max_running = Arc::new(AtomicUsize::new(0));
+
+        let outputs = do_jobs_with_limit(
+            (0..100).map(|i| {
+                let running = running.clone();
+                let max_running = max_running.clone();
+                async move {
+                    let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_running.fetch_max(now, Ordering::SeqCst);
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                    running.fetch_sub(1, Ordering::SeqCst);
+                    Ok::<_, ()>(i)
+                }
+            }),
+            LIMIT,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outputs.len(), 100);
+        assert_eq!(running.load(Ordering::SeqCst), 0);
+        let max_running = max_running.load(Ordering::SeqCst);
+        assert!(
+            max_running <= LIMIT,
+            "{max_running} jobs ran at once (limit {LIMIT})"
+        );
+    }
+
+    #[tokio::test]
+    async fn retry_counts_attempts() {
+        let calls = AtomicUsize::new(0);
+        let result: Result<(), ()> = retry(|| async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Err(())
+        })
+        .await;
+        assert!(result.is_err());
+        // Initial attempt + 5 retries
+        assert_eq!(calls.load(Ordering::SeqCst), 6);
+
+        let calls = AtomicUsize::new(0);
+        let result: Result<(), ()> = retry(|| async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        })
+        .await;
+        assert!(result.is_ok());
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+}
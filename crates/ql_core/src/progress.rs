@@ -1,4 +1,4 @@
-use std::fmt::Display;
+use std::{fmt::Display, sync::mpsc::Receiver};
 
 /// An enum representing the progress in downloading
 /// a Minecraft instance.
@@ -29,15 +29,15 @@ pub enum DownloadProgress {
 impl Display for DownloadProgress {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            DownloadProgress::DownloadingJsonManifest => write!(f, "Downloading Manifest JSON"),
-            DownloadProgress::DownloadingVersionJson => write!(f, "Downloading Version JSON"),
+            DownloadProgress::DownloadingJsonManifest => write!(f, "Downloading version manifest"),
+            DownloadProgress::DownloadingVersionJson => write!(f, "Downloading version JSON"),
             DownloadProgress::DownloadingAssets { progress, out_of } => {
-                write!(f, "Downloading asset {progress} / {out_of}")
+                write!(f, "Downloading assets ({progress}/{out_of})")
             }
             DownloadProgress::DownloadingLibraries { progress, out_of } => {
-                write!(f, "Downloading library {progress} / {out_of}")
+                write!(f, "Downloading libraries ({progress}/{out_of})")
             }
-            DownloadProgress::DownloadingJar => write!(f, "Downloading Game Jar file"),
+            DownloadProgress::DownloadingJar => write!(f, "Downloading Minecraft JAR"),
         }
     }
 }
@@ -94,6 +94,11 @@ pub trait Progress {
     fn get_message(&self) -> Option<String>;
     fn total() -> f32;
 
+    /// Whether this is the final progress update.
+    fn is_finished(&self) -> bool {
+        self.get_num() >= Self::total()
+    }
+
     fn into_generic(self) -> GenericProgress
     where
         Self: Sized,
@@ -137,4 +142,121 @@ impl Progress for GenericProgress {
     fn total() -> f32 {
         1.0
     }
+
+    fn is_finished(&self) -> bool {
+        self.has_finished
+    }
+}
+
+/// Combines multiple progress channels (of the same type)
+/// into a single progress value.
+///
+/// Useful when several operations (eg: Java installs for
+/// two instances being launched at once) run at the same time,
+/// but the UI only has room for one progress bar.
+///
+/// Channels that haven't sent anything yet aren't counted,
+/// so an operation that never starts doesn't hold the bar back.
+pub struct MultiProgress<T: Progress> {
+    channels: Vec<(ProgressId, Receiver<T>, Option<T>)>,
+    next_id: usize,
+    message: Option<String>,
+}
+
+/// A channel added to a [`MultiProgress`],
+/// for removing it later with [`MultiProgress::remove`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProgressId(usize);
+
+impl<T: Progress> Default for MultiProgress<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Progress> MultiProgress<T> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            channels: Vec::new(),
+            next_id: 0,
+            message: None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_recv(receiver: Receiver<T>) -> Self {
+        let mut this = Self::new();
+        this.add(receiver);
+        this
+    }
+
+    pub fn add(&mut self, receiver: Receiver<T>) -> ProgressId {
+        let id = ProgressId(self.next_id);
+        self.next_id += 1;
+        self.channels.push((id, receiver, None));
+        id
+    }
+
+    /// Stops tracking a channel (eg: its operation is done),
+    /// leaving the others as they are.
+    pub fn remove(&mut self, id: ProgressId) {
+        self.channels.retain(|(n, _, _)| *n != id);
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.channels.is_empty()
+    }
+
+    /// Receives any pending progress updates.
+    /// Returns `true` if anything was received.
+    pub fn tick(&mut self) -> bool {
+        let mut has_ticked = false;
+        for (_, receiver, latest) in &mut self.channels {
+            while let Ok(progress) = receiver.try_recv() {
+                if let Some(message) = progress.get_message() {
+                    self.message = Some(message);
+                }
+                *latest = Some(progress);
+                has_ticked = true;
+            }
+        }
+        has_ticked
+    }
+
+    /// Combined progress, from `0.0` to [`Progress::total`].
+    ///
+    /// This is an unweighted average of each channel's
+    /// [`Progress::get_num`], so a small operation counts
+    /// as much as a large one.
+    #[must_use]
+    pub fn get_num(&self) -> f32 {
+        let (done, count) = self
+            .channels
+            .iter()
+            .filter_map(|(_, _, n)| n.as_ref())
+            .fold((0.0, 0), |(done, count), n| (done + n.get_num(), count + 1));
+        if count == 0 { 0.0 } else { done / count as f32 }
+    }
+
+    /// The most recent message from any of the channels.
+    #[must_use]
+    pub fn get_message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    /// Whether every channel that has started
+    /// has reached the end.
+    ///
+    /// Returns `false` if nothing has started yet.
+    #[must_use]
+    pub fn has_finished(&self) -> bool {
+        let mut started = self
+            .channels
+            .iter()
+            .filter_map(|(_, _, n)| n.as_ref())
+            .peekable();
+        started.peek().is_some() && started.all(Progress::is_finished)
+    }
 }
@@ -2,7 +2,12 @@ use std::{
     collections::HashMap,
     fmt::{Display, Write},
     process::ExitStatus,
-    sync::{Arc, mpsc::Sender},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+        mpsc::Sender,
+    },
+    time::Duration,
 };
 
 use owo_colors::OwoColorize;
@@ -33,6 +38,8 @@ pub(crate) async fn read_logs(
     sender: Option<Sender<LogLine>>,
     instance: Instance,
     censors: Vec<String>,
+    hang_timeout: Option<Duration>,
+    killed_by_user: Arc<AtomicBool>,
 ) -> Result<(ExitStatus, Instance, Option<Diagnostic>), ReadError> {
     let r = {
         let mut c = child.lock().await;
@@ -47,12 +54,15 @@ pub(crate) async fn read_logs(
     let stdout = BufReader::new(stdout);
     let stderr = BufReader::new(stderr);
 
+    let last_output = Arc::new(std::sync::Mutex::new(tokio::time::Instant::now()));
+
     let stdout_read = tokio::spawn(read_log_from_stream(
         stdout,
         sender.clone(),
         censors.clone(),
         uses_xml,
         false,
+        last_output.clone(),
     ));
     let stderr_read = tokio::spawn(read_log_from_stream(
         stderr,
@@ -60,15 +70,30 @@ pub(crate) async fn read_logs(
         censors.clone(),
         false,
         false,
+        last_output.clone(),
     ));
 
     let status = loop {
-        tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        tokio::time::sleep(Duration::from_millis(500)).await;
         let mut child = child.lock().await;
         if let Some(status) = child.try_wait()? {
             break status;
         }
+
+        if let Some(timeout) = hang_timeout {
+            let elapsed = last_output.lock().unwrap().elapsed();
+            if elapsed >= timeout {
+                err!("Game produced no output for {elapsed:?}, killing it");
+                child.kill().await?;
+                stdout_read.abort();
+                stderr_read.abort();
+                return Err(ReadError::HangTimeout(timeout));
+            }
+        }
     };
+    if killed_by_user.load(Ordering::SeqCst) {
+        return Err(ReadError::ProcessKilled);
+    }
     let mut log_raw = stdout_read.await??;
     log_raw.extend(stderr_read.await??);
 
@@ -82,6 +107,7 @@ async fn read_log_from_stream<R: AsyncBufRead + Unpin>(
     censors: Vec<String>,
     uses_xml: bool,
     is_stderr: bool,
+    last_output: Arc<std::sync::Mutex<tokio::time::Instant>>,
 ) -> Result<Vec<String>, ReadError> {
     let mut stream = stream.lines();
     let mut xml_cache = String::new();
@@ -91,6 +117,7 @@ async fn read_log_from_stream<R: AsyncBufRead + Unpin>(
     let mut has_errored = false;
 
     while let Ok(Some(mut line)) = stream.next_line().await {
+        *last_output.lock().unwrap() = tokio::time::Instant::now();
         line = censor(&line, &censors);
         if uses_xml {
             xml_parse(
@@ -108,9 +135,9 @@ async fn read_log_from_stream<R: AsyncBufRead + Unpin>(
             send(
                 sender.as_ref(),
                 if is_stderr {
-                    LogLine::Error(line)
+                    LogLineKind::Error(line)
                 } else {
-                    LogLine::Message(line)
+                    LogLineKind::Message(line)
                 },
             );
         }
@@ -122,9 +149,9 @@ async fn read_log_from_stream<R: AsyncBufRead + Unpin>(
         send(
             sender.as_ref(),
             if remaining.contains("Minecraft Crash Report") {
-                LogLine::Error
+                LogLineKind::Error
             } else {
-                LogLine::Message
+                LogLineKind::Message
             }(remaining.replace('\t', "\n\t")),
         );
     }
@@ -147,8 +174,8 @@ fn censor(input: &str, censors: &[String]) -> String {
     }
 }
 
-fn send(sender: Option<&Sender<LogLine>>, msg: LogLine) {
-    if let LogLine::Info(LogEvent {
+fn send(sender: Option<&Sender<LogLine>>, msg: LogLineKind) {
+    if let LogLineKind::Info(LogEvent {
         message: Some(message),
         ..
     }) = &msg
@@ -157,6 +184,7 @@ fn send(sender: Option<&Sender<LogLine>>, msg: LogLine) {
             return;
         }
     }
+    let msg = LogLine::new(msg);
     if let Some(sender) = sender {
         _ = sender.send(msg);
     } else {
@@ -185,7 +213,7 @@ fn xml_parse(
             let other_text = xml[..start].trim();
             if !other_text.is_empty() {
                 log_raw.push(other_text.to_owned());
-                send(sender, LogLine::Message(other_text.to_owned()));
+                send(sender, LogLineKind::Message(other_text.to_owned()));
             }
             &xml[start..]
         }
@@ -199,7 +227,7 @@ fn xml_parse(
         Ok(mut log_event) => {
             log_event.fix_tabs();
             log_raw.push(log_event.to_string());
-            send(sender, LogLine::Info(log_event));
+            send(sender, LogLineKind::Info(log_event));
             xml_cache.clear();
         }
         Err(err) => {
@@ -220,42 +248,70 @@ async fn is_xml(instance: &Instance) -> Result<bool, ReadError> {
     Ok(json.logging.is_some())
 }
 
-/// Represents a line of log output.
-///
-/// # Variants
-/// - `Info(LogEvent)`: A log event. Contains advanced
-///   information about the log line like the timestamp,
-///   class name, level and thread.
-/// - `Message(String)`: A normal log message. Primarily
-///   used for non-XML logs (old Minecraft versions).
-/// - `Error(String)`: An error log message.
-pub enum LogLine {
-    Info(LogEvent),
-    Message(String),
-    Error(String),
+/// Represents a line of log output,
+/// along with the time it was read at.
+pub struct LogLine {
+    pub kind: LogLineKind,
+    /// When the launcher received this line
+    /// from the game's output.
+    ///
+    /// For XML logs, [`LogEvent::timestamp`] is the
+    /// time reported by the game itself, which may
+    /// be slightly earlier.
+    pub timestamp: chrono::DateTime<chrono::Local>,
 }
 
 impl LogLine {
+    #[must_use]
+    pub fn new(kind: LogLineKind) -> Self {
+        Self {
+            kind,
+            timestamp: chrono::Local::now(),
+        }
+    }
+
+    /// Returns the time this line was read,
+    /// formatted as `HH:MM:SS`.
+    #[must_use]
+    pub fn get_time(&self) -> String {
+        self.timestamp.format("%H:%M:%S").to_string()
+    }
+
     #[must_use]
     fn print_colored(&self) -> String {
-        match self {
-            LogLine::Info(event) => event.print_color(),
-            LogLine::Message(message) => message.clone(),
-            LogLine::Error(error) => error.bright_red().to_string(),
+        match &self.kind {
+            LogLineKind::Info(event) => event.print_color(),
+            LogLineKind::Message(message) => message.clone(),
+            LogLineKind::Error(error) => error.bright_red().to_string(),
         }
     }
 }
 
 impl Display for LogLine {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            LogLine::Info(event) => write!(f, "{event}"),
-            LogLine::Error(error) => write!(f, "{error}"),
-            LogLine::Message(message) => write!(f, "{message}"),
+        match &self.kind {
+            LogLineKind::Info(event) => write!(f, "{event}"),
+            LogLineKind::Error(error) => write!(f, "{error}"),
+            LogLineKind::Message(message) => write!(f, "{message}"),
         }
     }
 }
 
+/// The contents of a [`LogLine`].
+///
+/// # Variants
+/// - `Info(LogEvent)`: A log event. Contains advanced
+///   information about the log line like the timestamp,
+///   class name, level and thread.
+/// - `Message(String)`: A normal log message. Primarily
+///   used for non-XML logs (old Minecraft versions).
+/// - `Error(String)`: An error log message.
+pub enum LogLineKind {
+    Info(LogEvent),
+    Message(String),
+    Error(String),
+}
+
 const READ_ERR_PREFIX: &str = "while reading the game log:\n";
 
 #[derive(Debug, Error)]
@@ -270,6 +326,20 @@ pub enum ReadError {
     Join(#[from] JoinError),
     #[error("{0}")]
     Diagnostic(#[from] Diagnostic),
+    #[error("Instance was stopped")]
+    ProcessKilled,
+    #[error("Game appears hung — killed after {}", fmt_duration(*.0))]
+    HangTimeout(Duration),
+}
+
+fn fmt_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    match secs {
+        60 => "1 minute".to_owned(),
+        n if n % 60 == 0 => format!("{} minutes", n / 60),
+        1 => "1 second".to_owned(),
+        n => format!("{n} seconds"),
+    }
 }
 
 impl From<JsonFileError> for ReadError {
@@ -293,6 +363,12 @@ pub enum Diagnostic {
     OutOfStackSpace,
     #[error("Your mac's graphics drivers aren't working!\nThis is normal in virtual machines")]
     MacOSPixelFormat,
+    /// The server refused to start because `eula.txt`
+    /// doesn't contain `eula=true`.
+    #[error(
+        "You need to accept the Minecraft EULA to run this server\n(https://aka.ms/MinecraftEULA)"
+    )]
+    EulaNotAccepted,
 }
 
 impl Diagnostic {
@@ -302,7 +378,9 @@ impl Diagnostic {
             log.iter().any(|n| n.contains(msg))
         }
 
-        if c(log, "out of stack space")
+        if c(log, "You need to agree to the EULA") {
+            Some(Diagnostic::EulaNotAccepted)
+        } else if c(log, "out of stack space")
             || c(log, "OutOfMemoryError: unable to create new native thread")
         {
             Some(Diagnostic::OutOfStackSpace)
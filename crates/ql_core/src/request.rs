@@ -2,19 +2,27 @@ use std::{path::PathBuf, sync::OnceLock};
 
 use futures::StreamExt;
 use http_cache_reqwest::{CACacheManager, Cache, CacheMode, HttpCache, HttpCacheOptions};
-use reqwest::Client;
+use reqwest::{
+    Client,
+    header::{HeaderMap, HeaderName, HeaderValue},
+};
 use reqwest_middleware::{ClientBuilder, ClientWithMiddleware};
 use tokio_util::io::StreamReader;
 
 use crate::{
     DownloadFileError, IntoIoError, IntoJsonError, JsonDownloadError, LAUNCHER_CACHE_DIR,
-    RequestError, retry,
+    LAUNCHER_VERSION_NAME, RequestError, retry,
 };
 
 pub static CLIENT: OnceLock<ClientWithMiddleware> = OnceLock::new();
 
 pub fn build_middleware(path: PathBuf, cache: bool) -> ClientWithMiddleware {
-    ClientBuilder::new(Client::new())
+    // Some sites (CDNs, mirrors) reject requests without a user agent
+    let client = Client::builder()
+        .user_agent(format!("QuantumLauncher/{LAUNCHER_VERSION_NAME}"))
+        .build()
+        .unwrap_or_else(|_| Client::new());
+    ClientBuilder::new(client)
         .with(Cache(HttpCache {
             mode: if cache {
                 CacheMode::Default
@@ -31,9 +39,10 @@ pub fn build_middleware(path: PathBuf, cache: bool) -> ClientWithMiddleware {
 pub struct DownloadRequest<'a> {
     url: &'a str,
     user_agent: UserAgentKind,
+    headers: &'a [(&'a str, &'a str)],
 }
 
-impl DownloadRequest<'_> {
+impl<'a> DownloadRequest<'a> {
     pub fn user_agent_spoof(mut self) -> Self {
         self.user_agent = UserAgentKind::Spoofed;
         self
@@ -44,6 +53,13 @@ impl DownloadRequest<'_> {
         self
     }
 
+    /// Extra headers to send with the request.
+    /// These override the user agent, if set.
+    pub fn headers(mut self, headers: &'a [(&'a str, &'a str)]) -> Self {
+        self.headers = headers;
+        self
+    }
+
     async fn send(&self) -> Result<reqwest::Response, RequestError> {
         let client =
             CLIENT.get_or_init(|| build_middleware(LAUNCHER_CACHE_DIR.to_path_buf(), true));
@@ -64,6 +80,15 @@ impl DownloadRequest<'_> {
                 );
             }
         }
+        if !self.headers.is_empty() {
+            let mut map = HeaderMap::new();
+            for (name, value) in self.headers {
+                let name = HeaderName::from_bytes(name.as_bytes())
+                    .map_err(|_| RequestError::Message(format!("invalid header name: {name}")))?;
+                map.insert(name, HeaderValue::from_str(value)?);
+            }
+            get = get.headers(map);
+        }
         let response = get.send().await?;
         check_for_success(&response)?;
         Ok(response)
@@ -139,10 +164,24 @@ enum UserAgentKind {
     Spoofed,
 }
 
+/// Fetches multiple URLs concurrently, so they end up
+/// in the HTTP cache (if enabled) before they're actually needed.
+///
+/// Unlike [`crate::do_jobs`], one URL failing doesn't stop
+/// the others. Note: results aren't in the same order as `urls`.
+pub async fn url_cache_preload(urls: Vec<String>) -> Vec<Result<Vec<u8>, RequestError>> {
+    let jobs = urls.iter().map(|url| async move {
+        Ok::<_, std::convert::Infallible>(download(url).user_agent_ql().bytes().await)
+    });
+    let Ok(results) = crate::do_jobs(jobs).await;
+    results
+}
+
 pub fn download(url: &str) -> DownloadRequest<'_> {
     DownloadRequest {
         url,
         user_agent: UserAgentKind::None,
+        headers: &[],
     }
 }
 
@@ -2,7 +2,10 @@ use std::fmt::Display;
 
 use serde::{Deserialize, Serialize};
 
-use crate::{err, json::version::JavaVersionJson};
+use crate::{
+    err,
+    json::{VersionDetails, version::JavaVersionJson},
+};
 
 #[derive(Serialize, Deserialize, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Loader {
@@ -137,6 +140,38 @@ impl JavaVersion {
             Self::Java25 => None,
         }
     }
+
+    /// The minimum Java version needed to run
+    /// the given Minecraft version.
+    ///
+    /// Old versions without a `javaVersion` field
+    /// default to Java 8.
+    #[must_use]
+    pub fn required_for(version_json: &VersionDetails) -> Self {
+        version_json
+            .javaVersion
+            .clone()
+            .map_or(Self::Java8, Self::from)
+    }
+
+    /// Maps Mojang's Java runtime component IDs
+    /// (`javaVersion.component` in the version JSON)
+    /// to a Java version.
+    ///
+    /// Returns `None` for unknown IDs.
+    #[must_use]
+    pub fn from_component_id(id: &str) -> Option<Self> {
+        Some(match id {
+            "jre-legacy" => Self::Java8,
+            "java-runtime-alpha" => Self::Java16,
+            "java-runtime-beta" | "java-runtime-gamma" | "java-runtime-gamma-snapshot" => {
+                Self::Java17
+            }
+            "java-runtime-delta" => Self::Java21,
+            "java-runtime-epsilon" => Self::Java25,
+            _ => return None,
+        })
+    }
 }
 
 impl Display for JavaVersion {
@@ -158,7 +193,8 @@ impl From<JavaVersionJson> for JavaVersion {
             16 => Self::Java16,
             17 => Self::Java17,
             21 => Self::Java21,
-            _ => Self::Java25,
+            // Unknown major version, see if the runtime ID is known
+            _ => Self::from_component_id(&version.component).unwrap_or(Self::Java25),
         }
     }
 }
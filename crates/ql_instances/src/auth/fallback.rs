@@ -0,0 +1,160 @@
+//! Encrypted on-disk storage for account tokens, used when
+//! the system keyring isn't available.
+//!
+//! This mostly happens on headless Linux machines that don't
+//! run a Secret Service daemon (like `gnome-keyring`).
+//!
+//! # Security
+//! This is **weaker** than the system keyring, and only exists
+//! so that logging in works *at all* on such systems:
+//!
+//! - Tokens are encrypted with AES-256-GCM, using a key derived
+//!   (PBKDF2-HMAC-SHA256) from a machine-specific identifier
+//!   (`/etc/machine-id` on Linux) and the account's keyring name.
+//! - The key is *not* protected by any user secret. Anyone who
+//!   can read both the credential file and the machine ID
+//!   (any process running as your user, or root) can decrypt it.
+//! - What it does protect against is copying the `credentials`
+//!   folder to another machine (eg: through backups or by
+//!   sharing your launcher directory), or casually reading
+//!   tokens in a text editor.
+//!
+//! If the machine ID can't be read, a weaker fallback based on
+//! the hostname and launcher directory is used instead.
+
+use std::path::PathBuf;
+
+use ql_core::{LAUNCHER_DIR, pt};
+use ring::{
+    aead::{AES_256_GCM, Aad, LessSafeKey, NONCE_LEN, Nonce, UnboundKey},
+    pbkdf2,
+    rand::{SecureRandom, SystemRandom},
+};
+
+const PBKDF2_ITERATIONS: u32 = 100_000;
+
+fn credentials_path(keyring_name: &str) -> PathBuf {
+    let name: String = keyring_name
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || matches!(c, '#' | '-' | '_' | '.' | '@') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    LAUNCHER_DIR
+        .join("credentials")
+        .join(format!("{name}.key.enc"))
+}
+
+fn get_machine_id() -> String {
+    #[cfg(target_os = "linux")]
+    for path in ["/etc/machine-id", "/var/lib/dbus/machine-id"] {
+        if let Ok(id) = std::fs::read_to_string(path) {
+            let id = id.trim();
+            if !id.is_empty() {
+                return id.to_owned();
+            }
+        }
+    }
+
+    // Weak fallback: not secret at all, but still ties the
+    // file to this particular machine + launcher install.
+    let host = std::env::var("HOSTNAME")
+        .or_else(|_| std::env::var("COMPUTERNAME"))
+        .unwrap_or_default();
+    format!("{host}|{}", LAUNCHER_DIR.to_string_lossy())
+}
+
+fn derive_key(keyring_name: &str) -> Result<LessSafeKey, keyring::Error> {
+    let mut key = [0u8; 32];
+    pbkdf2::derive(
+        pbkdf2::PBKDF2_HMAC_SHA256,
+        std::num::NonZeroU32::new(PBKDF2_ITERATIONS).unwrap(),
+        format!("QuantumLauncher|{keyring_name}").as_bytes(),
+        get_machine_id().as_bytes(),
+        &mut key,
+    );
+    let key = UnboundKey::new(&AES_256_GCM, &key).map_err(|_| failure("invalid key"))?;
+    Ok(LessSafeKey::new(key))
+}
+
+fn failure(msg: impl Into<String>) -> keyring::Error {
+    let msg: String = msg.into();
+    keyring::Error::PlatformFailure(format!("credential file: {msg}").into())
+}
+
+/// Whether the keyring error means "there's no usable keyring"
+/// (as opposed to eg: "this entry doesn't exist").
+pub(super) fn is_unavailable(err: &keyring::Error) -> bool {
+    matches!(
+        err,
+        keyring::Error::NoStorageAccess(_) | keyring::Error::PlatformFailure(_)
+    )
+}
+
+/// Reads a token stored with [`write`].
+///
+/// Returns `Ok(None)` if there's no credential file
+/// for this account.
+pub(super) fn read(keyring_name: &str) -> Result<Option<String>, keyring::Error> {
+    let path = credentials_path(keyring_name);
+    let mut data = match std::fs::read(&path) {
+        Ok(n) => n,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(err) => return Err(keyring::Error::PlatformFailure(Box::new(err))),
+    };
+    if data.len() < NONCE_LEN {
+        return Err(failure("file is truncated"));
+    }
+
+    let key = derive_key(keyring_name)?;
+    let (nonce, ciphertext) = data.split_at_mut(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce).map_err(|_| failure("invalid nonce"))?;
+    let plaintext = key
+        .open_in_place(nonce, Aad::empty(), ciphertext)
+        .map_err(|_| failure("couldn't decrypt (was it copied from another machine?)"))?;
+
+    String::from_utf8(plaintext.to_vec())
+        .map(Some)
+        .map_err(|err| keyring::Error::BadEncoding(err.into_bytes()))
+}
+
+/// Encrypts and stores a token on disk.
+/// See the [module-level docs](self) for the trade-offs.
+pub(super) fn write(keyring_name: &str, token: &str) -> Result<(), keyring::Error> {
+    let path = credentials_path(keyring_name);
+    pt!("Keyring unavailable, storing credentials in encrypted file instead");
+
+    let key = derive_key(keyring_name)?;
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| failure("couldn't generate nonce"))?;
+
+    let mut data = token.as_bytes().to_vec();
+    key.seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut data)
+        .map_err(|_| failure("couldn't encrypt"))?;
+
+    let mut out = nonce.to_vec();
+    out.extend_from_slice(&data);
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|err| keyring::Error::PlatformFailure(Box::new(err)))?;
+    }
+    std::fs::write(&path, out).map_err(|err| keyring::Error::PlatformFailure(Box::new(err)))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        _ = std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600));
+    }
+    Ok(())
+}
+
+/// Deletes the credential file, if any.
+pub(super) fn delete(keyring_name: &str) {
+    _ = std::fs::remove_file(credentials_path(keyring_name));
+}
@@ -4,6 +4,7 @@ use std::fmt::Display;
 
 mod alt;
 pub mod authlib;
+mod fallback;
 pub mod ms;
 pub mod yggdrasil;
 pub use authlib::get_authlib_injector;
@@ -27,6 +28,13 @@ impl AccountData {
         self.account_type.add_suffix_to_name(&self.nice_username)
     }
 
+    /// See [`AccountType::get_skin_service_url`]
+    #[must_use]
+    pub fn get_skin_service_url(&self) -> String {
+        self.account_type
+            .get_skin_service_url(&self.uuid, &self.nice_username)
+    }
+
     #[must_use]
     pub fn get_authlib_url(&self) -> Option<&'static str> {
         match self.account_type {
@@ -85,17 +93,21 @@ impl AccountType {
         }
     }
 
+    fn get_keyring_name(self, username: &str) -> String {
+        format!(
+            "{username}{}",
+            match self {
+                AccountType::Microsoft => "",
+                AccountType::ElyBy => "#elyby",
+                AccountType::LittleSkin => "#littleskin",
+            }
+        )
+    }
+
     fn get_keyring_entry(self, username: &str) -> Result<keyring::Entry, KeyringError> {
         Ok(keyring::Entry::new(
             "QuantumLauncher",
-            &format!(
-                "{username}{}",
-                match self {
-                    AccountType::Microsoft => "",
-                    AccountType::ElyBy => "#elyby",
-                    AccountType::LittleSkin => "#littleskin",
-                }
-            ),
+            &self.get_keyring_name(username),
         )?)
     }
 
@@ -108,6 +120,27 @@ impl AccountType {
         }
     }
 
+    /// Returns the profile endpoint for a player, which contains
+    /// the (base64-encoded) `textures` property holding
+    /// the skin and cape URLs.
+    ///
+    /// `uuid` should be the player's UUID without dashes.
+    /// ely.by's `skinsystem` looks players up by `name` instead.
+    #[must_use]
+    pub fn get_skin_service_url(&self, uuid: &str, name: &str) -> String {
+        match self {
+            AccountType::Microsoft => format!(
+                "https://sessionserver.mojang.com/session/minecraft/profile/{uuid}?unsigned=false"
+            ),
+            AccountType::ElyBy => {
+                format!("https://skinsystem.ely.by/profile/{name}?unsigned=false")
+            }
+            AccountType::LittleSkin => format!(
+                "https://littleskin.cn/api/yggdrasil/sessionserver/session/minecraft/profile/{uuid}?unsigned=false"
+            ),
+        }
+    }
+
     #[must_use]
     pub fn strip_name(self, name: &str) -> &str {
         match self {
@@ -175,16 +208,58 @@ Now after this, in the sidebar, right click it and click "Set as Default""#
     }
 }
 
+/// Reads the stored refresh token of an account.
+///
+/// If the system keyring is unavailable (eg: headless Linux
+/// without a Secret Service daemon), or the token isn't in it,
+/// this falls back to the encrypted credential file
+/// written by [`write_refresh_token`].
 pub fn read_refresh_token(
     username: &str,
     account_type: AccountType,
 ) -> Result<String, KeyringError> {
-    let entry = account_type.get_keyring_entry(username)?;
-    let refresh_token = entry.get_password()?;
-    Ok(refresh_token)
+    let keyring_name = account_type.get_keyring_name(username);
+    let err = match account_type
+        .get_keyring_entry(username)
+        .and_then(|entry| Ok(entry.get_password()?))
+    {
+        Ok(refresh_token) => return Ok(refresh_token),
+        Err(err) => err,
+    };
+
+    if fallback::is_unavailable(&err.0) || matches!(err.0, keyring::Error::NoEntry) {
+        if let Some(refresh_token) = fallback::read(&keyring_name)? {
+            return Ok(refresh_token);
+        }
+    }
+    Err(err)
+}
+
+/// Stores the refresh token of an account in the system keyring.
+///
+/// If the keyring is unavailable, this stores it in an
+/// encrypted file in `QuantumLauncher/credentials/` instead.
+/// This is less secure, see the notes in `auth/fallback.rs`.
+pub fn write_refresh_token(
+    username: &str,
+    account_type: AccountType,
+    refresh_token: &str,
+) -> Result<(), KeyringError> {
+    let keyring_name = account_type.get_keyring_name(username);
+    match account_type
+        .get_keyring_entry(username)
+        .and_then(|entry| Ok(entry.set_password(refresh_token)?))
+    {
+        Ok(()) => Ok(()),
+        Err(err) if fallback::is_unavailable(&err.0) => {
+            Ok(fallback::write(&keyring_name, refresh_token)?)
+        }
+        Err(err) => Err(err),
+    }
 }
 
 pub fn logout(username: &str, account_type: AccountType) -> Result<(), String> {
+    fallback::delete(&account_type.get_keyring_name(username));
     let entry = account_type.get_keyring_entry(username).strerr()?;
     if let Err(err) = entry.delete_credential() {
         err!("Couldn't remove {account_type} account credential (Username: {username}):\n{err}");
@@ -239,8 +239,7 @@ pub async fn login_refresh(
 
     let data: RefreshResponse = serde_json::from_str(&response).json(response)?;
 
-    let entry = keyring::Entry::new("QuantumLauncher", &username)?;
-    entry.set_password(&data.refresh_token)?;
+    super::write_refresh_token(&username, AccountType::Microsoft, &data.refresh_token)?;
 
     let data = login_3_xbox(
         AuthTokenResponse {
@@ -304,8 +303,11 @@ pub async fn login_3_xbox(
         }
     }
 
-    let entry = keyring::Entry::new("QuantumLauncher", &final_details.name)?;
-    entry.set_password(&data.refresh_token)?;
+    super::write_refresh_token(
+        &final_details.name,
+        AccountType::Microsoft,
+        &data.refresh_token,
+    )?;
 
     let data = AccountData {
         access_token: Some(minecraft.access_token),
@@ -59,8 +59,11 @@ pub async fn login_new(
         }
     };
 
-    let entry = account_type.get_keyring_entry(&email_or_username)?;
-    entry.set_password(&account_response.accessToken)?;
+    super::write_refresh_token(
+        &email_or_username,
+        account_type,
+        &account_response.accessToken,
+    )?;
 
     Ok(Account::Account(AccountData {
         access_token: Some(account_response.accessToken.clone()),
@@ -89,7 +92,6 @@ pub async fn login_refresh(
     account_type: AccountType,
 ) -> Result<AccountData, Error> {
     pt!("Refreshing {account_type} account...");
-    let entry = account_type.get_keyring_entry(&email_or_username)?;
 
     let mut value = serde_json::json!({
         "accessToken": refresh_token,
@@ -105,7 +107,11 @@ pub async fn login_refresh(
     let text = response.text().await?;
 
     let account_response = serde_json::from_str::<AccountResponse>(&text).json(text.clone())?;
-    entry.set_password(&account_response.accessToken)?;
+    super::write_refresh_token(
+        &email_or_username,
+        account_type,
+        &account_response.accessToken,
+    )?;
 
     Ok(AccountData {
         access_token: Some(account_response.accessToken.clone()),
@@ -1,5 +1,4 @@
 use crate::auth::alt::OauthError;
-use keyring;
 use ql_core::request::check_for_success;
 use ql_core::{CLIENT, IntoJsonError};
 use serde::{Deserialize, Serialize};
@@ -97,11 +96,11 @@ pub async fn poll_device_token(
     }
 
     // Store Minecraft token in keyring (same convention as password flow)
-    keyring::Entry::new(
-        "QuantumLauncher",
-        &format!("{}#littleskin", user_info.username),
-    )
-    .and_then(|e| e.set_password(&mc_token_resp.access_token))?;
+    crate::auth::write_refresh_token(
+        &user_info.username,
+        crate::auth::AccountType::LittleSkin,
+        &mc_token_resp.access_token,
+    )?;
 
     // Build account data compatible with existing flows
     Ok(super::Account::Account(super::AccountData {
@@ -4,7 +4,10 @@ use std::{
     sync::mpsc::Sender,
 };
 
-use crate::json_profiles::ProfileJson;
+use crate::{
+    instance::launch::{check_disk_space, error::OutOfDiskSpaceError},
+    json_profiles::{PROFILE_FILE_NAMES, ProfileJson},
+};
 use ql_core::{
     DownloadFileError, DownloadProgress, IntoIoError, IntoJsonError, IoError, JsonError, ListEntry,
     RequestError, do_jobs, download,
@@ -43,6 +46,16 @@ pub enum DownloadError {
         "{DOWNLOAD_ERR_PREFIX}tried to remove natives outside folder. POTENTIAL SECURITY RISK AVOIDED"
     )]
     NativesOutsideDirRemove,
+    #[error(
+        "{DOWNLOAD_ERR_PREFIX}native library is corrupted (hash mismatch): {library}\nexpected sha1: {expected}\ngot sha1: {got}\n\nTry creating the instance again"
+    )]
+    NativeHashMismatch {
+        library: String,
+        expected: String,
+        got: String,
+    },
+    #[error("{DOWNLOAD_ERR_PREFIX}{0}")]
+    OutOfDiskSpace(#[from] OutOfDiskSpaceError),
 }
 
 impl_3_errs_jri!(DownloadError, Json, Request, Io);
@@ -76,23 +89,25 @@ impl GameDownloader {
         instance_name: &str,
         version: &ListEntry,
         sender: Option<Sender<DownloadProgress>>,
+        download_assets: bool,
     ) -> Result<GameDownloader, DownloadError> {
-        let Some(instance_dir) = GameDownloader::new_get_instance_dir(instance_name).await? else {
+        let instance_dir = LAUNCHER_DIR.join("instances").join(instance_name);
+        if exists(&instance_dir).await {
             return Err(DownloadError::InstanceAlreadyExists(
                 instance_name.to_owned(),
             ));
-        };
+        }
         let version_json =
-            match GameDownloader::new_download_version_json(version, sender.as_ref()).await {
-                Ok(n) => n,
-                Err(DownloadError::VersionNotFoundInManifest(v)) => {
-                    fs::remove_dir_all(&instance_dir)
-                        .await
-                        .path(&instance_dir)?;
-                    return Err(DownloadError::VersionNotFoundInManifest(v));
-                }
-                Err(err) => return Err(err),
-            };
+            GameDownloader::new_download_version_json(version, sender.as_ref()).await?;
+
+        // Checked before creating the instance folder,
+        // so that failing doesn't leave an empty instance behind
+        let needed = version_json.estimate_download_size(download_assets);
+        check_disk_space(&instance_dir, needed).await?;
+
+        fs::create_dir_all(&instance_dir)
+            .await
+            .path(&instance_dir)?;
 
         Ok(Self {
             instance_dir,
@@ -131,26 +146,39 @@ impl GameDownloader {
 
         let jar_path = version_dir.join(format!("{}.jar", self.version_json.get_id()));
 
-        download(&self.version_json.downloads.client.url)
-            .path(&jar_path)
+        file_utils::download_with_resume(&self.version_json.downloads.client.url, &jar_path, None)
             .await?;
 
         Ok(())
     }
 
     pub async fn download_logging_config(&self) -> Result<(), DownloadError> {
-        if let Some(logging) = &self.version_json.logging {
-            let log_config_name = format!("logging-{}", logging.client.file.id);
-            let config_path = self.instance_dir.join(log_config_name);
-
-            download(&logging.client.file.url)
-                .path(&config_path)
-                .await?;
-        }
+        let (Some(logging), Some(url)) = (
+            &self.version_json.logging,
+            self.version_json.get_logging_config_url(),
+        ) else {
+            return Ok(());
+        };
+        let log_config_name = format!("logging-{}", logging.client.file.id);
+        let config_path = self.instance_dir.join(log_config_name);
+
+        let sha1 = self
+            .version_json
+            .get_logging_config_sha1()
+            .unwrap_or_default();
+        let config = file_utils::download_file_verified(url, sha1, false).await?;
+        fs::write(&config_path, config).await.path(config_path)?;
         Ok(())
     }
 
-    pub async fn download_assets(&self) -> Result<(), DownloadError> {
+    /// Downloads the assets of this version to the
+    /// shared `assets/dir` folder, skipping ones that
+    /// are already there.
+    ///
+    /// If `verify_hashes` is set, existing assets are also
+    /// checked against their SHA-1 hashes (slower, but
+    /// catches corrupted files).
+    pub async fn download_assets(&self, verify_hashes: bool) -> Result<(), DownloadError> {
         info!("Downloading assets");
         let asset_index: AssetIndex =
             file_utils::download_file_to_json(&self.version_json.assetIndex.url, false).await?;
@@ -181,8 +209,18 @@ impl GameDownloader {
         let bar = &indicatif::ProgressBar::new(out_of as u64);
         let progress_num = &Mutex::new(0);
 
+        let skipped = &Mutex::new(0);
         let results = asset_index.objects.values().map(|asset| async move {
-            asset.download(assets_objects_path).await?;
+            // Assets are shared between instances, so
+            // don't redownload what's already there
+            if asset
+                .exists_and_valid(assets_objects_path, verify_hashes)
+                .await
+            {
+                *skipped.lock().await += 1;
+            } else {
+                asset.download(assets_objects_path).await?;
+            }
 
             let mut progress = progress_num.lock().await;
             *progress += 1;
@@ -201,6 +239,11 @@ impl GameDownloader {
         });
 
         _ = do_jobs(results).await?;
+
+        let skipped = *skipped.lock().await;
+        if skipped > 0 {
+            pt!("{skipped}/{out_of} assets were already downloaded");
+        }
         Ok(())
     }
 
@@ -214,8 +257,10 @@ impl GameDownloader {
             .await
             .path(&assets_indexes_path)?;
 
-        let assets_indexes_json_path =
-            assets_indexes_path.join(format!("{}.json", self.version_json.assetIndex.id));
+        let assets_indexes_json_path = assets_indexes_path.join(format!(
+            "{}.json",
+            self.version_json.get_id_for_asset_index()
+        ));
         tokio::fs::write(
             &assets_indexes_json_path,
             serde_json::to_string(&asset_index).json_to()?,
@@ -295,17 +340,20 @@ impl GameDownloader {
         Ok(())
     }*/
 
+    /// Creates `launcher_profiles.json` (and its Microsoft Store
+    /// variant) in `.minecraft`, for loader installers that need it.
+    /// See [`ProfileJson`] for more info.
     pub async fn create_profiles_json(&self) -> Result<(), DownloadError> {
         let profile_json = ProfileJson::default();
-
         let profile_json = serde_json::to_string(&profile_json).json_to()?;
-        let profile_json_path = self
-            .instance_dir
-            .join(".minecraft")
-            .join("launcher_profiles.json");
-        tokio::fs::write(&profile_json_path, profile_json)
-            .await
-            .path(profile_json_path)?;
+
+        let dot_minecraft = self.instance_dir.join(".minecraft");
+        for name in PROFILE_FILE_NAMES {
+            let profile_json_path = dot_minecraft.join(name);
+            tokio::fs::write(&profile_json_path, &profile_json)
+                .await
+                .path(profile_json_path)?;
+        }
 
         Ok(())
     }
@@ -350,23 +398,6 @@ impl GameDownloader {
         Ok(download(&version.url).json().await?)
     }
 
-    async fn new_get_instance_dir(instance_name: &str) -> Result<Option<PathBuf>, IoError> {
-        let instances_dir = LAUNCHER_DIR.join("instances");
-        tokio::fs::create_dir_all(&instances_dir)
-            .await
-            .path(&instances_dir)?;
-
-        let current_instance_dir = instances_dir.join(instance_name);
-        if exists(&current_instance_dir).await {
-            return Ok(None);
-        }
-        tokio::fs::create_dir_all(&current_instance_dir)
-            .await
-            .path(&current_instance_dir)?;
-
-        Ok(Some(current_instance_dir))
-    }
-
     pub fn send_progress(&self, progress: DownloadProgress, print: bool) {
         if let Some(ref sender) = self.sender {
             if sender.send(progress).is_ok() {
@@ -14,9 +14,7 @@ use ql_core::{
     DownloadProgress, IntoIoError, IoError, do_jobs, err, file_utils, info,
     json::{
         VersionDetails,
-        version::{
-            Library, LibraryClassifier, LibraryDownloadArtifact, LibraryDownloads, LibraryExtract,
-        },
+        version::{Library, LibraryClassifier, LibraryDownloadArtifact, LibraryDownloads},
     },
     pt,
 };
@@ -130,7 +128,7 @@ impl GameDownloader {
                 .await?;
             }
             if let Some(classifiers) = classifiers {
-                self.download_library_native(classifiers, &libraries_dir, library.extract.as_ref())
+                self.download_library_native(library, classifiers, &libraries_dir)
                     .await?;
             }
         } else if let Some(artifact) = artifact_fallback {
@@ -233,11 +231,12 @@ impl GameDownloader {
 
     async fn download_library_native(
         &self,
+        library: &Library,
         classifiers: &BTreeMap<String, LibraryClassifier>,
         libraries_dir: &Path,
-        extract: Option<&LibraryExtract>,
     ) -> Result<(), DownloadError> {
         let natives_dir = libraries_dir.join("natives");
+        let name = library.name.as_deref().unwrap_or_default();
 
         for (os, download) in classifiers {
             if os == "sources" {
@@ -291,10 +290,11 @@ impl GameDownloader {
                 "4: classifiers".blue(),
                 download.url.bright_black()
             );
-            self.extract_file(download.url.clone()).await?;
+            self.extract_natives(download.url.clone(), Some(&download.sha1), name)
+                .await?;
         }
 
-        if let Some(extract) = extract {
+        if let Some(extract) = &library.extract {
             for exclusion in &extract.exclude {
                 let path = natives_dir.join(exclusion);
 
@@ -315,7 +315,19 @@ impl GameDownloader {
         Ok(())
     }
 
-    async fn extract_file(&self, mut url: String) -> Result<(), DownloadError> {
+    /// Downloads a natives jar and extracts it to `libraries/natives`.
+    ///
+    /// If `sha1` is provided (and non-empty), the downloaded jar is
+    /// verified against it, and the hash is recorded in
+    /// [`NATIVES_MANIFEST`] so that already-extracted natives
+    /// are skipped next time.
+    async fn extract_natives(
+        &self,
+        mut url: String,
+        sha1: Option<&str>,
+        library: &str,
+    ) -> Result<(), DownloadError> {
+        let original_url = url.clone();
         if url
             == "https://github.com/theofficialgman/lwjgl3-binaries-arm64/raw/lwjgl-3.1.6/lwjgl-jemalloc-natives-linux.jar"
         {
@@ -347,26 +359,55 @@ impl GameDownloader {
         {
             return Ok(());
         }
-        let file_bytes = match file_utils::download_file_to_bytes(&url, false).await {
-            Ok(n) => n,
+
+        let extract_path = self.instance_dir.join("libraries/natives");
+        // Hashes only apply to the original URL, not our replacements
+        let sha1 = sha1.filter(|n| !n.is_empty() && url == original_url);
+        if let Some(sha1) = sha1 {
+            if is_natives_extracted(&extract_path, sha1).await {
+                pt!(
+                    "  {} {library} (already extracted)",
+                    "Skipping".bright_black()
+                );
+                return Ok(());
+            }
+        }
+
+        let (file_bytes, sha1) = match file_utils::download_file_to_bytes(&url, false).await {
+            Ok(n) => (n, sha1),
             #[cfg(any(
                 all(target_os = "linux", target_arch = "aarch64"),
                 feature = "simulate_linux_arm64"
             ))]
-            Err(ql_core::RequestError::DownloadError { code, .. }) if code.as_u16() == 404 => {
+            Err(ql_core::RequestError::DownloadError { code, .. }) if code.as_u16() == 404 => (
                 file_utils::download_file_to_bytes(
                     &url.replace("linux.jar", "linux-arm64.jar"),
                     false,
                 )
-                .await?
-            }
+                .await?,
+                None,
+            ),
             Err(err) => Err(err)?,
         };
 
-        let extract_path = self.instance_dir.join("libraries/natives");
+        if let Some(expected) = sha1 {
+            let got = file_utils::get_sha1(&file_bytes);
+            if !got.eq_ignore_ascii_case(expected) {
+                return Err(DownloadError::NativeHashMismatch {
+                    library: library.to_owned(),
+                    expected: expected.to_owned(),
+                    got,
+                });
+            }
+        }
+
         file_utils::extract_zip_archive(Cursor::new(file_bytes), &extract_path, true)
             .await
             .map_err(DownloadError::NativesExtractError)?;
+
+        if let Some(sha1) = sha1 {
+            mark_natives_extracted(&extract_path, sha1).await?;
+        }
         Ok(())
     }
 
@@ -380,29 +421,10 @@ impl GameDownloader {
     ) -> Result<(), DownloadError> {
         let name = library.name.as_deref().unwrap_or_default();
 
-        let Some(natives) = &library.natives else {
+        let Some(natives_name) = library.get_natives_name() else {
             return Ok(());
         };
 
-        cfg_if!(
-            if #[cfg(any(
-                target_arch = "aarch64",
-                target_arch = "arm",
-                target_arch = "x86",
-                feature = "simulate_linux_arm64",
-                feature = "simulate_macos_arm64",
-                feature = "simulate_linux_arm32",
-            ))] {
-                let Some(natives_name) = natives.get(&format!("{OS_NAME}-{ARCH}")) else {
-                    return Ok(());
-                };
-            } else {
-                let Some(natives_name) = natives.get(OS_NAME) else {
-                    return Ok(());
-                };
-            }
-        );
-
         if library
             .name
             .as_deref()
@@ -423,12 +445,13 @@ impl GameDownloader {
             }
         }
 
-        let natives_url = if let Some(natives) = classifiers.and_then(|n| n.get(natives_name)) {
-            natives.url.clone()
-        } else {
-            let url = &artifact.url[..artifact.url.len() - 4];
-            format!("{url}-{natives_name}.jar")
-        };
+        let (natives_url, sha1) =
+            if let Some(natives) = classifiers.and_then(|n| n.get(&natives_name)) {
+                (natives.url.clone(), Some(natives.sha1.as_str()))
+            } else {
+                let url = &artifact.url[..artifact.url.len() - 4];
+                (format!("{url}-{natives_name}.jar"), None)
+            };
 
         pt!(
             "  Natives ({}): {}\n    {}",
@@ -436,7 +459,7 @@ impl GameDownloader {
             name.bright_black(),
             natives_url.bright_black()
         );
-        self.extract_file(natives_url).await?;
+        self.extract_natives(natives_url, sha1, name).await?;
 
         Ok(())
     }
@@ -480,13 +503,40 @@ impl GameDownloader {
                 "3: based on name".yellow(),
                 name.bright_black()
             );
-            self.extract_file(artifact.url.clone()).await?;
+            self.extract_natives(artifact.url.clone(), Some(&artifact.sha1), name)
+                .await?;
         }
 
         Ok(())
     }
 }
 
+/// A file in `libraries/natives` listing the SHA-1 hashes
+/// of natives jars that have already been extracted.
+const NATIVES_MANIFEST: &str = ".ql_natives";
+
+async fn is_natives_extracted(natives_dir: &Path, sha1: &str) -> bool {
+    fs::read_to_string(natives_dir.join(NATIVES_MANIFEST))
+        .await
+        .is_ok_and(|n| n.lines().any(|line| line.eq_ignore_ascii_case(sha1)))
+}
+
+async fn mark_natives_extracted(natives_dir: &Path, sha1: &str) -> Result<(), IoError> {
+    use tokio::io::AsyncWriteExt;
+
+    let path = natives_dir.join(NATIVES_MANIFEST);
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .await
+        .path(&path)?;
+    file.write_all(format!("{sha1}\n").as_bytes())
+        .await
+        .path(&path)?;
+    Ok(())
+}
+
 async fn finalize_natives_directory(dir: &Path, root: &Path) -> Result<(), IoError> {
     async fn is_dir_empty(dir: &Path) -> Result<bool, IoError> {
         let mut entries = fs::read_dir(dir).await.path(dir)?;
@@ -53,7 +53,7 @@ pub async fn create_instance(
     }
 
     let mut game_downloader =
-        GameDownloader::new(&instance_name, &version, progress_sender).await?;
+        GameDownloader::new(&instance_name, &version, progress_sender, download_assets).await?;
 
     tokio::try_join!(
         game_downloader.download_logging_config(),
@@ -63,7 +63,7 @@ pub async fn create_instance(
     game_downloader.library_extras().await?;
 
     if download_assets {
-        game_downloader.download_assets().await?;
+        game_downloader.download_assets(false).await?;
     }
 
     game_downloader
@@ -118,7 +118,7 @@ pub async fn repeat_stage(
             downloader.download_libraries().await.strerr()?;
         }
         DownloadProgress::DownloadingAssets { .. } => {
-            downloader.download_assets().await.strerr()?;
+            downloader.download_assets(true).await.strerr()?;
         }
         DownloadProgress::DownloadingJar => {
             downloader.download_jar().await.strerr()?;
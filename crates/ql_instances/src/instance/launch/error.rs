@@ -1,5 +1,5 @@
 use ql_java_handler::JavaInstallError;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use ql_core::{IoError, JsonError, RequestError, impl_3_errs_jri, json::VersionDetails};
 
@@ -7,6 +7,19 @@ use crate::{download::DownloadError, jarmod::JarModError};
 
 const GAME_ERR_PREFIX: &str = "while launching game:\n";
 
+/// Returned by [`super::check_disk_space`].
+/// Shared by [`GameLaunchError`] and [`DownloadError`].
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "not enough disk space!\nNeeds {} MB, but only {} MB is available\n\nFree up some space and try again",
+    needed / 1_000_000,
+    available / 1_000_000
+)]
+pub struct OutOfDiskSpaceError {
+    pub available: u64,
+    pub needed: u64,
+}
+
 #[derive(Debug, thiserror::Error)]
 pub enum GameLaunchError {
     #[error("{GAME_ERR_PREFIX}{0}")]
@@ -31,8 +44,16 @@ pub enum GameLaunchError {
     Semver(#[from] semver::Error),
     #[error("{GAME_ERR_PREFIX}couldn't convert PathBuf to string: {0:?}")]
     PathBufToString(PathBuf),
-    #[error("{GAME_ERR_PREFIX}couldn't run java command: {0}")]
-    CommandError(std::io::Error, PathBuf),
+    #[error("{GAME_ERR_PREFIX}{}", command_error_msg(.0, .1))]
+    CommandError(#[source] std::io::Error, PathBuf),
+    /// Major versions: `(required, found)`
+    #[error(
+        "{GAME_ERR_PREFIX}the selected Java is too old for this version!\nNeeds Java {0} or newer (found: Java {1})\n\nGo to Edit Instance and pick a newer Java, or remove the override"
+    )]
+    JavaVersionTooOld(usize, usize),
+
+    #[error("{GAME_ERR_PREFIX}{0}")]
+    OutOfDiskSpace(#[from] OutOfDiskSpaceError),
 
     #[error("{GAME_ERR_PREFIX}{0}")]
     Download(#[from] DownloadError),
@@ -61,4 +82,71 @@ pub enum GameLaunchError {
 const FORGE_UPGRADE_MESSAGE: &str = r"outdated forge install. Please uninstall and reinstall.
 Select your instance, go to Mods -> Uninstall Forge, then Install Forge.";
 
+fn command_error_msg(err: &std::io::Error, path: &Path) -> String {
+    if err.kind() == std::io::ErrorKind::NotFound {
+        format!(
+            "Java binary not found at {}\n\nTry reinstalling Java in Launcher Settings",
+            path.display()
+        )
+    } else {
+        format!("couldn't run java command ({}): {err}", path.display())
+    }
+}
+
 impl_3_errs_jri!(GameLaunchError, Json, Request, Io);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error;
+
+    fn source_string(err: &GameLaunchError) -> Option<String> {
+        err.source().map(ToString::to_string)
+    }
+
+    #[test]
+    fn wrapped_errors_are_exposed_as_source() {
+        let io = IoError::LauncherDirNotFound;
+        let expected = io.to_string();
+        assert_eq!(source_string(&io.into()), Some(expected));
+
+        let json = JsonError::To {
+            error: serde_json::from_str::<u8>("x").unwrap_err(),
+        };
+        let expected = json.to_string();
+        assert_eq!(source_string(&json.into()), Some(expected));
+
+        let semver = semver::Version::parse("not a version").unwrap_err();
+        let expected = semver.to_string();
+        assert_eq!(source_string(&semver.into()), Some(expected));
+
+        let space = OutOfDiskSpaceError {
+            available: 0,
+            needed: 1,
+        };
+        let expected = space.to_string();
+        assert_eq!(source_string(&space.into()), Some(expected));
+
+        let command = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        let expected = command.to_string();
+        let err = GameLaunchError::CommandError(command, PathBuf::from("java"));
+        assert_eq!(source_string(&err), Some(expected));
+    }
+
+    #[test]
+    fn leaf_errors_have_no_source() {
+        for err in [
+            GameLaunchError::UsernameHasSpaces,
+            GameLaunchError::UsernameIsEmpty,
+            GameLaunchError::InstanceNotFound("test".to_owned()),
+            GameLaunchError::InstanceIncomplete,
+            GameLaunchError::PathBufToString(PathBuf::from("test")),
+            GameLaunchError::JavaVersionTooOld(17, 8),
+            GameLaunchError::InvalidToken,
+            GameLaunchError::ForgeInstallUpgradeTransformPathError,
+            GameLaunchError::ForgeInstallUpgradeStripPrefixError,
+        ] {
+            assert!(err.source().is_none(), "{err:?} shouldn't have a source");
+        }
+    }
+}
@@ -5,18 +5,19 @@ use crate::{
 };
 use ql_core::{
     CLASSPATH_SEPARATOR, GenericProgress, Instance, IntoIoError, IntoJsonError, IoError,
-    JsonFileError, LAUNCHER_DIR, Loader, err,
+    JsonFileError, LAUNCHER_DIR, Loader, do_jobs, err,
     file_utils::{self, exists},
     info,
     json::{
-        FabricJSON, GlobalSettings, InstanceConfigJson, JsonOptifine, V_1_5_2, V_1_12_2,
-        V_PAULSCODE_LAST, V_PRECLASSIC_LAST, VersionDetails, forge, version::Library,
+        EffectiveSettings, FabricJSON, GlobalSettings, InstanceConfigJson, JsonOptifine, V_1_5_2,
+        V_1_12_2, V_PAULSCODE_LAST, V_PRECLASSIC_LAST, VersionDetails, forge, version::Library,
     },
     pt,
 };
 use ql_java_handler::{JavaVersion, get_java_binary};
 use std::{
     collections::HashSet,
+    convert::Infallible,
     io::ErrorKind,
     path::{Path, PathBuf},
     process::Stdio,
@@ -26,6 +27,9 @@ use tokio::process::Command;
 
 use super::{error::GameLaunchError, replace_var};
 
+/// Rough size of an extracted Java install, in bytes.
+const JAVA_INSTALL_SIZE: u64 = 300_000_000;
+
 pub struct GameLauncher {
     username: String,
     instance_name: Arc<str>,
@@ -44,10 +48,8 @@ pub struct GameLauncher {
 
     config: InstanceConfigJson,
     pub version_json: VersionDetails,
-    /// Launcher-wide instance settings. These
-    /// can be overridden by `config_json.global_settings`.
-    global_settings: Option<GlobalSettings>,
-    extra_java_args: Vec<String>,
+    /// Instance settings merged with the launcher-wide defaults.
+    settings: EffectiveSettings,
 }
 
 impl GameLauncher {
@@ -78,6 +80,10 @@ impl GameLauncher {
         let mut version_json = VersionDetails::load(&instance).await?;
         version_json.apply_tweaks(&instance).await?;
 
+        let settings = global_settings
+            .unwrap_or_default()
+            .merge_with_instance(&config, &extra_java_args);
+
         Ok(Self {
             username,
             instance_name,
@@ -86,11 +92,23 @@ impl GameLauncher {
             minecraft_dir,
             config,
             version_json,
-            global_settings,
-            extra_java_args,
+            settings,
         })
     }
 
+    /// The directory the game runs in (`game_directory`).
+    ///
+    /// - Client: `QuantumLauncher/instances/NAME/.minecraft/`
+    /// - Server: `QuantumLauncher/servers/NAME/`
+    #[must_use]
+    pub fn get_working_directory(&self) -> &Path {
+        if self.config.is_server() {
+            &self.instance_dir
+        } else {
+            &self.minecraft_dir
+        }
+    }
+
     pub fn init_game_arguments(
         &mut self,
         account_details: Option<&AccountData>,
@@ -123,14 +141,11 @@ impl GameLauncher {
 
         // Add custom resolution arguments if specified
         // Priority: Instance-specific setting > Global default > Minecraft default
-        let (width_to_use, height_to_use) =
-            self.config.get_window_size(self.global_settings.as_ref());
-
-        if let Some(width) = width_to_use {
+        if let Some(width) = self.settings.window_width {
             game_arguments.push("--width".to_owned());
             game_arguments.push(width.to_string());
         }
-        if let Some(height) = height_to_use {
+        if let Some(height) = self.settings.window_height {
             game_arguments.push("--height".to_owned());
             game_arguments.push(height.to_string());
         }
@@ -148,8 +163,9 @@ impl GameLauncher {
         for arg in game_arguments.iter_mut() {
             replace_var(arg, "auth_player_name", &self.username);
             replace_var(arg, "version_name", self.version_json.get_id());
-            let Some(minecraft_dir_path) = self.minecraft_dir.to_str() else {
-                return Err(GameLaunchError::PathBufToString(self.minecraft_dir.clone()));
+            let working_dir = self.get_working_directory();
+            let Some(minecraft_dir_path) = working_dir.to_str() else {
+                return Err(GameLaunchError::PathBufToString(working_dir.to_owned()));
             };
             replace_var(arg, "game_directory", minecraft_dir_path);
 
@@ -187,7 +203,11 @@ impl GameLauncher {
                 },
             );
             replace_var(arg, "version_type", "release");
-            replace_var(arg, "assets_index_name", &self.version_json.assetIndex.id);
+            replace_var(
+                arg,
+                "assets_index_name",
+                self.version_json.get_id_for_asset_index(),
+            );
             replace_var(arg, "user_properties", "{}");
         }
         Ok(())
@@ -198,7 +218,7 @@ impl GameLauncher {
 
         let old_assets_path_v2 = launcher_dir
             .join("assets")
-            .join(&self.version_json.assetIndex.id);
+            .join(self.version_json.get_id_for_asset_index());
 
         let old_assets_path_v1 = self.instance_dir.join("assets");
         let assets_path = launcher_dir.join("assets/dir");
@@ -243,11 +263,11 @@ impl GameLauncher {
             .to_str()
             .ok_or(GameLaunchError::PathBufToString(natives_path.clone()))?;
 
-        // TODO: deal with self.version_json.arguments.jvm (currently ignored)
         let mut args: Vec<String> = self
-            .config
-            .get_java_args(&self.extra_java_args)
-            .into_iter()
+            .settings
+            .java_args
+            .iter()
+            .cloned()
             .filter(|arg| !arg.trim().is_empty())
             .chain([
                 "-Dminecraft.launcher.brand=minecraft-launcher".to_owned(),
@@ -256,7 +276,7 @@ impl GameLauncher {
                 format!("-Djna.tmpdir={natives_path}"),
                 format!("-Dorg.lwjgl.system.SharedLibraryExtractPath={natives_path}"),
                 format!("-Dio.netty.native.workdir={natives_path}"),
-                self.config.get_ram_argument(),
+                self.settings.get_ram_argument(),
             ])
             .collect();
 
@@ -274,7 +294,16 @@ impl GameLauncher {
             args.push("-Xss1M".to_owned());
         }
 
-        if cfg!(target_os = "macos") {
+        // Platform-specific arguments from the version JSON
+        // (eg: `-XX:HeapDumpPath=...` on Windows)
+        args.extend(
+            self.version_json
+                .get_arguments_jvm()
+                .into_iter()
+                .filter(|arg| !is_jvm_argument_handled_by_launcher(arg)),
+        );
+
+        if cfg!(target_os = "macos") && self.needs_start_on_first_thread() {
             args.push("-XstartOnFirstThread".to_owned());
         }
 
@@ -283,6 +312,18 @@ impl GameLauncher {
         Ok(args)
     }
 
+    /// Whether `-XstartOnFirstThread` should be passed on macOS.
+    ///
+    /// LWJGL 3 (GLFW) requires it, but LWJGL 2 (1.12.2 and below)
+    /// uses AWT which can deadlock with it.
+    /// Can be overridden with `force_x_first_thread` in `config.json`.
+    fn needs_start_on_first_thread(&self) -> bool {
+        self.config.force_x_first_thread.unwrap_or_else(|| {
+            self.version_json.id.ends_with("-lwjgl3")
+                || !self.version_json.is_before_or_eq(V_1_12_2)
+        })
+    }
+
     /// Adds BetaCraft proxy to fix missing/incorrect sounds
     /// in old versions of Minecraft.
     ///
@@ -297,13 +338,13 @@ impl GameLauncher {
         #[allow(deprecated)]
         if self.config.omniarchive.is_some() {
             args.push("-Dhttp.proxyHost=betacraft.uk".to_owned());
-            if self.version_json.id.starts_with("c0.") {
+            if self.version_json.is_classic() {
                 // Classic
                 args.push("-Dhttp.proxyPort=11701".to_owned());
             } else if self.version_json.id.starts_with("b1.9") {
                 // Beta 1.9
                 args.push("-Dhttp.proxyPort=11706".to_owned());
-            } else if self.version_json.id.starts_with("b1.") {
+            } else if self.version_json.is_beta() {
                 // Beta 1.0 - 1.8.1
                 args.push("-Dhttp.proxyPort=11705".to_owned());
             } else if self.version_json.id.starts_with("1.") {
@@ -374,8 +415,25 @@ impl GameLauncher {
         Ok(serde_json::from_str(&fabric_json).json(fabric_json)?)
     }
 
+    /// Where Forge/NeoForge files are stored
+    /// (see [`InstanceConfigJson::forge_dir_override`]).
+    pub(crate) fn get_forge_dir(&self) -> PathBuf {
+        self.config.get_forge_dir(&self.instance_dir)
+    }
+
+    /// Forge classpath entries and arguments point to `../forge`
+    /// (relative to `.minecraft`). If the Forge directory was
+    /// overridden, they're pointed there instead.
+    fn relocate_forge_paths(&self, paths: &str) -> String {
+        if self.config.forge_dir_override.is_none() {
+            return paths.to_owned();
+        }
+        let forge_dir = self.get_forge_dir();
+        paths.replace("../forge/", &format!("{}/", forge_dir.to_string_lossy()))
+    }
+
     async fn get_forge_json(&self) -> Result<forge::JsonDetails, JsonFileError> {
-        let json_path = self.instance_dir.join("forge/details.json");
+        let json_path = self.get_forge_dir().join("details.json");
         let json = tokio::fs::read_to_string(&json_path)
             .await
             .path(json_path)?;
@@ -430,7 +488,11 @@ impl GameLauncher {
                 &CLASSPATH_SEPARATOR.to_string(),
             );
             // I think this argument is only used by forge? Not sure
-            replace_var(argument, "library_directory", "../forge/libraries");
+            replace_var(
+                argument,
+                "library_directory",
+                &self.relocate_forge_paths("../forge/libraries"),
+            );
             replace_var(argument, "version_name", self.version_json.get_id());
         }
     }
@@ -463,7 +525,7 @@ impl GameLauncher {
         } else if let Some((optifine_json, _)) = &optifine_json {
             optifine_json.mainClass.clone()
         } else {
-            self.version_json.mainClass.clone()
+            self.version_json.get_main_class().to_owned()
         }
     }
 
@@ -513,6 +575,12 @@ impl GameLauncher {
             .ok_or(GameLaunchError::PathBufToString(jar_path.clone()))?;
         class_path.push_str(jar_path);
 
+        // User-provided extra JARs (`classpath_extras` in `config.json`)
+        for extra in self.config.get_class_path_extras(&self.instance_dir) {
+            class_path.push(CLASSPATH_SEPARATOR);
+            class_path.push_str(&extra.to_string_lossy());
+        }
+
         Ok(class_path)
     }
 
@@ -606,12 +674,12 @@ impl GameLauncher {
             return Ok(());
         };
 
-        let classpath_path = self.instance_dir.join("forge/classpath.txt");
+        let classpath_path = self.get_forge_dir().join("classpath.txt");
         let forge_classpath = tokio::fs::read_to_string(&classpath_path)
             .await
             .path(classpath_path)?;
 
-        let mut new_classpath = forge_classpath.clone();
+        let mut new_classpath = self.relocate_forge_paths(&forge_classpath);
 
         // WTF: This is horrible but necessary
         //
@@ -627,7 +695,10 @@ impl GameLauncher {
             if let Some(jvm) = &args.jvm {
                 if let Some(module_path) = get_after_p(jvm) {
                     for lib in module_path
-                        .replace("${library_directory}", "../forge/libraries")
+                        .replace(
+                            "${library_directory}",
+                            &self.relocate_forge_paths("../forge/libraries"),
+                        )
                         .replace("${classpath_separator}", &CLASSPATH_SEPARATOR.to_string())
                         .split(CLASSPATH_SEPARATOR)
                     {
@@ -643,7 +714,7 @@ impl GameLauncher {
 
         class_path.push_str(&new_classpath);
 
-        let classpath_entries_path = self.instance_dir.join("forge/clean_classpath.txt");
+        let classpath_entries_path = self.get_forge_dir().join("clean_classpath.txt");
         if let Ok(forge_classpath_entries) =
             tokio::fs::read_to_string(&classpath_entries_path).await
         {
@@ -674,29 +745,61 @@ impl GameLauncher {
             None,
         );
 
-        for library in self
+        let natives = self.version_json.get_natives_classifier();
+        if let Some(natives) = &natives {
+            pt!("Natives: {natives}");
+        }
+        let libraries: Vec<&Library> = self
             .version_json
             .libraries
             .iter()
-            .filter(|n| n.is_allowed())
-        {
-            self.add_entry_to_classpath(
-                classpath_entries,
-                class_path,
-                &downloader,
-                library,
-                main_class,
-            )
-            .await?;
+            .filter(|n| n.is_allowed() && is_natives_for_platform(n, natives.as_deref()))
+            .collect();
+
+        // Check (and download missing) libraries concurrently,
+        // the classpath itself is built in order afterwards.
+        let jobs = libraries
+            .iter()
+            .filter(|library| !is_library_already_added(classpath_entries, library))
+            .map(|library| self.ensure_library_downloaded(&downloader, library));
+        let Ok(_) = do_jobs(jobs).await;
+
+        for library in libraries {
+            self.add_entry_to_classpath(classpath_entries, class_path, library, main_class)?;
         }
         Ok(())
     }
 
-    async fn add_entry_to_classpath(
+    /// Downloads `library` if it isn't present in the instance.
+    /// Failures are logged and skipped, so this never errors.
+    async fn ensure_library_downloaded(
+        &self,
+        downloader: &GameDownloader,
+        library: &Library,
+    ) -> Result<(), Infallible> {
+        let Some(artifact) = library.get_artifact() else {
+            return Ok(());
+        };
+        let library_path = self
+            .instance_dir
+            .join("libraries")
+            .join(artifact.get_path());
+
+        if !exists(&library_path).await {
+            pt!("library {library_path:?} not found! Downloading...");
+            if let Err(err) = downloader.download_library(library, Some(&artifact)).await {
+                err!("Couldn't download library! Skipping...\n{err}");
+            } else if !exists(&library_path).await {
+                err!("Library still doesn't exist... failed?");
+            }
+        }
+        Ok(())
+    }
+
+    fn add_entry_to_classpath(
         &self,
         classpath_entries: &mut HashSet<String>,
         class_path: &mut String,
-        downloader: &GameDownloader,
         library: &Library,
         main_class: &str,
     ) -> Result<(), GameLaunchError> {
@@ -718,20 +821,11 @@ impl GameLauncher {
             .join("libraries")
             .join(artifact.get_path());
 
-        if !exists(&library_path).await {
-            pt!("library {library_path:?} not found! Downloading...");
-            if let Err(err) = downloader.download_library(library, Some(&artifact)).await {
-                err!("Couldn't download library! Skipping...\n{err}");
-            } else if !library_path.exists() {
-                err!("Library still doesn't exist... failed?");
-            }
-        }
         #[allow(unused_mut)]
         let Some(mut library_path) = library_path.to_str() else {
             return Err(GameLaunchError::PathBufToString(library_path));
         };
-        if main_class != "org.mcphackers.launchwrapper.Launch" && library_path.contains("20230311")
-        {
+        if needs_launchwrapper_fix(library_path, main_class) {
             return Ok(());
         }
         if library_path.contains("paulscode")
@@ -763,22 +857,22 @@ impl GameLauncher {
 
         if let Some(java_override) = self.config.get_java_override() {
             info!("Java (override): {java_override:?}\n");
-            return Ok((
-                Command::new(
-                    ql_java_handler::find_java_bin_in_dir(which_java, &java_override).await?,
-                ),
-                java_override,
-            ));
+            let program = ql_java_handler::find_java_bin_in_dir(which_java, &java_override).await?;
+            self.check_java_override_version(&program).await?;
+            return Ok((Command::new(program), java_override));
         }
 
         let version = if let Some(version) = self.config.java_override_version {
             version.into()
-        } else if let Some(version) = self.version_json.javaVersion.clone() {
-            version.into()
         } else {
-            JavaVersion::Java8
+            JavaVersion::required_for(&self.version_json)
         };
 
+        let java_dir = LAUNCHER_DIR.join("java_installs").join(version.to_string());
+        if !exists(&java_dir).await {
+            super::check_disk_space(&java_dir, JAVA_INSTALL_SIZE).await?;
+        }
+
         let program = get_java_binary(
             version,
             which_java,
@@ -789,22 +883,19 @@ impl GameLauncher {
         Ok((Command::new(&program), program))
     }
 
-    pub async fn cleanup_junk_files(&self) -> Result<(), GameLaunchError> {
-        let forge_dir = self.instance_dir.join("forge");
-
-        if exists(&forge_dir).await {
-            delete_junk_file(&forge_dir, "ClientInstaller.class").await?;
-            delete_junk_file(&forge_dir, "ClientInstaller.java").await?;
-            delete_junk_file(&forge_dir, "ForgeInstaller.class").await?;
-            delete_junk_file(&forge_dir, "ForgeInstaller.java").await?;
-            delete_junk_file(&forge_dir, "launcher_profiles.json").await?;
-            delete_junk_file(&forge_dir, "launcher_profiles_microsoft_store.json").await?;
-
-            let versions_dir = forge_dir.join("versions");
-            delete_junk_dir(&versions_dir.join(self.version_json.get_id())).await?;
-            delete_junk_dir(&versions_dir.join(&self.version_json.id)).await?;
+    /// Makes sure a user-provided Java isn't older than
+    /// what the game needs, otherwise it crashes with a
+    /// cryptic `UnsupportedClassVersionError`.
+    ///
+    /// If the version can't be detected, this does nothing.
+    async fn check_java_override_version(&self, program: &Path) -> Result<(), GameLaunchError> {
+        let required = usize::from(self.version_json.get_java_major_version());
+        let Some(found) = get_java_major_version(program).await else {
+            return Ok(());
+        };
+        if found < required {
+            return Err(GameLaunchError::JavaVersionTooOld(required, found));
         }
-
         Ok(())
     }
 
@@ -815,20 +906,10 @@ impl GameLauncher {
     ) -> Result<(Command, PathBuf), GameLaunchError> {
         let (mut command, mut path) = self.get_java_command().await?;
 
-        let prefix_commands = self.config.build_launch_prefix(
-            self.global_settings
-                .as_ref()
-                .and_then(|n| n.pre_launch_prefix.as_deref())
-                .unwrap_or_default(),
-        );
+        let prefix_commands = &self.settings.pre_launch_prefix;
         if prefix_commands.is_empty() {
             // No prefix, use normal Java command
-            command.args(
-                java_arguments
-                    .iter()
-                    .chain(game_arguments.iter())
-                    .filter(|n| !n.is_empty()),
-            );
+            push_arguments(&mut command, &java_arguments, &game_arguments);
         } else {
             info!("Prefix: {prefix_commands:?}");
 
@@ -839,44 +920,38 @@ impl GameLauncher {
                 new_command.args(&prefix_commands[1..]);
             }
             new_command.arg(original_java_path);
-            new_command.args(
-                java_arguments
-                    .iter()
-                    .chain(game_arguments.iter())
-                    .filter(|n| !n.is_empty()),
-            );
+            push_arguments(&mut new_command, &java_arguments, &game_arguments);
 
             command = new_command;
             path = PathBuf::from(&prefix_commands[0]);
         }
 
-        command.current_dir(&self.minecraft_dir);
+        command.current_dir(self.get_working_directory());
         if self.config.enable_logger.unwrap_or(true) {
             command.stdout(Stdio::piped()).stderr(Stdio::piped());
         }
 
         #[cfg(all(target_arch = "aarch64", target_os = "linux"))]
-        {
-            // Minecraft 21w19a release date (1.17 snapshot)
-            // Not sure if this is the right place to start,
-            // but the env var started being required sometime between 1.16.5 and 1.17
-            const MC_1_17: &str = "2021-05-12T11:19:15+00:00";
-
-            if let (Ok(dt), Ok(v1_17)) = (
-                chrono::DateTime::parse_from_rfc3339(&self.version_json.releaseTime),
-                chrono::DateTime::parse_from_rfc3339(MC_1_17),
-            ) {
-                // On Raspberry Pi (aarch64 linux), the game crashes with some GL
-                // error. Adding this environment variable fixes it.
-                if dt >= v1_17 {
-                    command.env("MESA_GL_VERSION_OVERRIDE", "3.3");
-                }
-                // I don't know if this is the perfect solution,
-                // contact me if there's a better way
-            }
-        }
+        self.apply_mesa_gl_override(&mut command);
+
         Ok((command, path))
     }
+
+    /// On Raspberry Pi (aarch64 linux), Mesa only advertises
+    /// an old OpenGL version by default, even though the GPU
+    /// can handle more. LWJGL 3 then fails to create the
+    /// OpenGL 3.2+ context that 1.17+ needs, and the game crashes
+    /// with some GL error.
+    ///
+    /// Setting `MESA_GL_VERSION_OVERRIDE=3.3` fixes it.
+    /// I don't know if this is the perfect solution,
+    /// contact me if there's a better way.
+    #[cfg(all(target_arch = "aarch64", target_os = "linux"))]
+    fn apply_mesa_gl_override(&self, command: &mut Command) {
+        if self.version_json.requires_opengl_3_3() {
+            command.env("MESA_GL_VERSION_OVERRIDE", "3.3");
+        }
+    }
 }
 
 async fn get_instance_dir(instance_name: &str) -> Result<PathBuf, GameLaunchError> {
@@ -894,26 +969,107 @@ async fn get_instance_dir(instance_name: &str) -> Result<PathBuf, GameLaunchErro
         .await
         .path(&instances_folder_dir)?;
 
-    let instance_dir = instances_folder_dir.join(instance_name);
-    if !exists(&instance_dir).await {
+    let instance = Instance::client(instance_name);
+    if !instance.exists_async().await {
         return Err(GameLaunchError::InstanceNotFound(instance_name.to_owned()));
     }
-    Ok(instance_dir)
+    Ok(instance.get_instance_path())
 }
 
-async fn delete_junk_file(forge_dir: &Path, path: &str) -> Result<(), GameLaunchError> {
+/// Deletes leftover Forge installer files from a client instance.
+///
+/// Returns the number of files/folders that were deleted.
+///
+/// # Errors
+/// - The instance's `details.json` couldn't be read.
+/// - A junk file couldn't be deleted.
+pub async fn cleanup_junk_files(instance: Instance) -> Result<usize, GameLaunchError> {
+    let forge_dir = InstanceConfigJson::read(&instance)
+        .await?
+        .get_forge_dir(&instance.get_instance_path());
+    if !exists(&forge_dir).await {
+        return Ok(0);
+    }
+    let version_json = VersionDetails::load(&instance).await?;
+
+    let mut deleted = 0;
+    for file in [
+        "ClientInstaller.class",
+        "ClientInstaller.java",
+        "ForgeInstaller.class",
+        "ForgeInstaller.java",
+        "launcher_profiles.json",
+        "launcher_profiles_microsoft_store.json",
+    ] {
+        deleted += usize::from(delete_junk_file(&forge_dir, file).await?);
+    }
+
+    let versions_dir = forge_dir.join("versions");
+    deleted += usize::from(delete_junk_dir(&versions_dir.join(version_json.get_id())).await?);
+    deleted += usize::from(delete_junk_dir(&versions_dir.join(&version_json.id)).await?);
+
+    Ok(deleted)
+}
+
+async fn delete_junk_file(forge_dir: &Path, path: &str) -> Result<bool, GameLaunchError> {
     let path = forge_dir.join(path);
-    if exists(&path).await {
-        tokio::fs::remove_file(&path).await.path(path)?;
+    if !exists(&path).await {
+        return Ok(false);
     }
-    Ok(())
+    tokio::fs::remove_file(&path).await.path(path)?;
+    Ok(true)
 }
 
-async fn delete_junk_dir(dir: &Path) -> Result<(), GameLaunchError> {
-    if dir.is_dir() {
-        tokio::fs::remove_dir_all(&dir).await.path(dir)?;
+async fn delete_junk_dir(dir: &Path) -> Result<bool, GameLaunchError> {
+    if !dir.is_dir() {
+        return Ok(false);
     }
-    Ok(())
+    tokio::fs::remove_dir_all(&dir).await.path(dir)?;
+    Ok(true)
+}
+
+/// Build ID of the libraries that are only meant to be used
+/// alongside [MCPHackers LaunchWrapper](https://github.com/MCPHackers/LaunchWrapper).
+const LAUNCHWRAPPER_ONLY_BUILD: &str = "20230311";
+
+/// Whether a library should be left out of the classpath
+/// because it belongs to LaunchWrapper, but the game isn't
+/// being launched through LaunchWrapper
+/// (eg: a custom main class was set).
+fn needs_launchwrapper_fix(library_path: &str, main_class: &str) -> bool {
+    main_class != "org.mcphackers.launchwrapper.Launch"
+        && library_path.contains(LAUNCHWRAPPER_ONLY_BUILD)
+}
+
+/// Whether an argument from `arguments.jvm` in the version JSON
+/// is one that the launcher already passes (or decides) by itself,
+/// like the natives path, classpath or `-XstartOnFirstThread`.
+fn is_jvm_argument_handled_by_launcher(arg: &str) -> bool {
+    arg.contains("${") || matches!(arg, "-cp" | "-Xss1M" | "-XstartOnFirstThread")
+}
+
+/// Whether a library with the same name (ignoring version)
+/// was already put on the classpath, eg: by a mod loader.
+fn is_library_already_added(classpath_entries: &HashSet<String>, library: &Library) -> bool {
+    library
+        .name
+        .as_deref()
+        .and_then(remove_version_from_library)
+        .is_some_and(|name| classpath_entries.contains(&name))
+}
+
+/// Whether `library` isn't a natives library (like
+/// `org.lwjgl:lwjgl:3.3.3:natives-linux`) for a different
+/// platform than `natives`, the classifier used by this version.
+fn is_natives_for_platform(library: &Library, natives: Option<&str>) -> bool {
+    let Some(natives) = natives else {
+        return true;
+    };
+    library
+        .name
+        .as_deref()
+        .and_then(|n| n.split(':').nth(3))
+        .is_none_or(|classifier| !classifier.starts_with("natives-") || classifier == natives)
 }
 
 fn remove_version_from_library(library: &str) -> Option<String> {
@@ -979,6 +1135,23 @@ async fn migrate_to_new_assets_path(
     Ok(())
 }
 
+/// Runs `java -version` and parses the major version
+/// (`1.8.0_292` -> 8, `17.0.2` -> 17).
+async fn get_java_major_version(program: &Path) -> Option<usize> {
+    let output = Command::new(program).arg("-version").output().await.ok()?;
+    // `java -version` prints to stderr
+    let output = String::from_utf8_lossy(&output.stderr);
+    let version = output.split('"').nth(1)?;
+
+    let mut parts = version.split(['.', '_', '-', '+']);
+    let major: usize = parts.next()?.parse().ok()?;
+    if major == 1 {
+        parts.next()?.parse().ok()
+    } else {
+        Some(major)
+    }
+}
+
 fn get_after_p(args: &[String]) -> Option<String> {
     args.iter()
         .position(|arg| arg == "-p")
@@ -1000,39 +1173,215 @@ fn remove_substring(original: &str, to_remove: &str) -> Option<String> {
     }
 }
 
+/// Game arguments that don't take a value.
+const GAME_FLAG_ARGS: &[&str] = &["--demo", "--disableSkinFix", "--fullscreen"];
+
 fn deduplicate_game_args(arr1: &[String], arr2: &[String]) -> Vec<String> {
-    // Helper function to insert key-value pairs in order
-    fn insert_pairs(arr: &[String], result: &mut Vec<String>, seen_keys: &mut HashSet<String>) {
-        let arr: Vec<String> = arr.iter().filter(|n| !n.is_empty()).cloned().collect();
-        for i in (0..arr.len()).step_by(2) {
+    // Helper function to insert key-value pairs (or single flags) in order
+    fn insert_pairs(arr: &[String], result: &mut Vec<(String, Option<String>)>) {
+        let arr: Vec<&String> = arr.iter().filter(|n| !n.is_empty()).collect();
+        let mut i = 0;
+        while i < arr.len() {
             let key = arr[i].clone();
-            let value = arr.get(i + 1).cloned();
-            if seen_keys.contains(&key) {
-                if let Some(value) = value {
-                    // Update value if the key already exists in result (i.e., in case of conflict, overwrite)
-                    if let Some(pos) = result.iter().position(|x| x == &key) {
-                        if let Some(spot) = result.get_mut(pos + 1) {
-                            *spot = value; // Update the value for this key
-                        }
-                    }
-                }
+            let is_flag = GAME_FLAG_ARGS.contains(&key.as_str())
+                || arr.get(i + 1).is_none_or(|next| next.starts_with("--"));
+            let value = if is_flag {
+                i += 1;
+                None
             } else {
-                result.push(key.clone());
-                if let Some(value) = value {
-                    result.push(value.clone());
+                i += 2;
+                Some(arr[i - 1].clone())
+            };
+
+            if let Some((_, old_value)) = result.iter_mut().find(|(k, _)| *k == key) {
+                // In case of conflict, overwrite
+                if value.is_some() {
+                    *old_value = value;
                 }
-                seen_keys.insert(key);
+            } else {
+                result.push((key, value));
             }
         }
     }
 
     let mut result = Vec::new();
-    let mut seen_keys = HashSet::new();
 
-    insert_pairs(arr1, &mut result, &mut seen_keys);
+    insert_pairs(arr1, &mut result);
     // Second array overwrites first
-    insert_pairs(arr2, &mut result, &mut seen_keys);
+    insert_pairs(arr2, &mut result);
 
-    // HashMap -> Vec<String> (key, value, key, value, ...)
+    // (key, value) -> Vec<String> (key, value, key, value, ...)
     result
+        .into_iter()
+        .flat_map(|(key, value)| std::iter::once(key).chain(value))
+        .collect()
+}
+
+/// Adds the Java and game arguments to the command.
+///
+/// Each argument is passed as-is (no shell involved), so paths
+/// with spaces (eg: in the classpath, on Windows) don't need quoting.
+/// The whole classpath is a single argument after `-cp`.
+fn push_arguments(command: &mut Command, java_arguments: &[String], game_arguments: &[String]) {
+    command.args(
+        java_arguments
+            .iter()
+            .chain(game_arguments.iter())
+            .filter(|n| !n.is_empty()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ql_core::{InstanceKind, json::instance_config::VersionInfo};
+
+    #[test]
+    fn classpath_with_spaces_is_single_argument() {
+        let class_path = [
+            r"C:\Users\John Doe\AppData\Roaming\QuantumLauncher\instances\My Instance\libraries\a b.jar",
+            r"C:\Users\John Doe\AppData\Roaming\QuantumLauncher\instances\My Instance\.minecraft\versions\My Instance\My Instance.jar",
+        ]
+        .join(&CLASSPATH_SEPARATOR.to_string());
+
+        let java_arguments = vec![
+            "-Xmx2048M".to_owned(),
+            String::new(),
+            "-cp".to_owned(),
+            class_path.clone(),
+            "net.minecraft.client.main.Main".to_owned(),
+        ];
+        let game_arguments = vec!["--username".to_owned(), "Player".to_owned()];
+
+        let mut command = Command::new("java");
+        push_arguments(&mut command, &java_arguments, &game_arguments);
+
+        let args: Vec<&std::ffi::OsStr> = command.as_std().get_args().collect();
+        assert_eq!(
+            args,
+            [
+                "-Xmx2048M",
+                "-cp",
+                class_path.as_str(),
+                "net.minecraft.client.main.Main",
+                "--username",
+                "Player",
+            ]
+        );
+    }
+
+    #[test]
+    fn fabric_classpath_skips_patch_overrides() {
+        let dir = tempfile::tempdir().unwrap();
+        let instance_dir = dir.path().to_owned();
+
+        let fabric_json: FabricJSON = serde_json::from_value(serde_json::json!({
+            "mainClass": "net.fabricmc.loader.impl.launch.knot.KnotClient",
+            "libraries": [
+                { "name": "net.fabricmc:intermediary:1.20.1" },
+                { "name": "net.fabricmc:sponge-mixin:0.12.5" },
+            ],
+        }))
+        .unwrap();
+
+        let kept = fabric_json.libraries[1].get_path();
+        let kept_path = instance_dir.join("libraries").join(&kept);
+        std::fs::create_dir_all(kept_path.parent().unwrap()).unwrap();
+        std::fs::write(&kept_path, b"").unwrap();
+
+        let mut version_json = VersionDetails::default();
+        version_json
+            .q_patch_overrides
+            .push("net.fabricmc.intermediary".to_owned());
+
+        let launcher = GameLauncher {
+            username: "Player".to_owned(),
+            instance_name: Arc::from("test"),
+            java_install_progress_sender: None,
+            minecraft_dir: instance_dir.join(".minecraft"),
+            instance_dir,
+            config: InstanceConfigJson::new(
+                InstanceKind::Client,
+                false,
+                VersionInfo::new("1.20.1"),
+            ),
+            version_json,
+            settings: EffectiveSettings::default(),
+        };
+
+        let mut class_path = String::new();
+        let mut entries = HashSet::new();
+        launcher
+            .classpath_fabric_and_quilt(Some(&fabric_json), &mut class_path, &mut entries)
+            .unwrap();
+
+        assert!(!class_path.contains("intermediary"));
+        assert!(class_path.contains(&kept));
+        assert!(!entries.contains("net.fabricmc:intermediary"));
+    }
+
+    #[test]
+    fn working_directory_per_kind() {
+        let instance_dir = PathBuf::from("instances").join("test");
+        let make_launcher = |kind: InstanceKind| GameLauncher {
+            username: "Player".to_owned(),
+            instance_name: Arc::from("test"),
+            java_install_progress_sender: None,
+            minecraft_dir: instance_dir.join(".minecraft"),
+            instance_dir: instance_dir.clone(),
+            config: InstanceConfigJson::new(kind, false, VersionInfo::new("1.20.1")),
+            version_json: VersionDetails::default(),
+            settings: EffectiveSettings::default(),
+        };
+
+        assert_eq!(
+            make_launcher(InstanceKind::Client).get_working_directory(),
+            instance_dir.join(".minecraft")
+        );
+        assert_eq!(
+            make_launcher(InstanceKind::Server).get_working_directory(),
+            instance_dir
+        );
+    }
+
+    #[test]
+    fn launchwrapper_fix() {
+        let lib =
+            "libraries/org/mcphackers/launchwrapper/1.0-20230311/launchwrapper-1.0-20230311.jar";
+        assert!(!needs_launchwrapper_fix(
+            lib,
+            "org.mcphackers.launchwrapper.Launch"
+        ));
+        assert!(needs_launchwrapper_fix(
+            lib,
+            "net.minecraft.client.Minecraft"
+        ));
+        assert!(!needs_launchwrapper_fix(
+            "libraries/org/ow2/asm/asm/9.3/asm-9.3.jar",
+            "net.minecraft.client.Minecraft"
+        ));
+    }
+
+    #[test]
+    fn deduplicate_game_args_with_flags() {
+        let to_vec = |arr: &[&str]| arr.iter().map(|n| (*n).to_owned()).collect::<Vec<_>>();
+
+        let arr1 = to_vec(&["--username", "Steve", "--demo", "--width", "854"]);
+        let arr2 = to_vec(&["--width", "1280", "--fullscreen"]);
+        assert_eq!(
+            deduplicate_game_args(&arr1, &arr2),
+            to_vec(&[
+                "--username",
+                "Steve",
+                "--demo",
+                "--width",
+                "1280",
+                "--fullscreen"
+            ])
+        );
+
+        // Odd length, trailing flag isn't dropped
+        let arr1 = to_vec(&["--version", "1.8.9", "--disableSkinFix"]);
+        assert_eq!(deduplicate_game_args(&arr1, &[]), arr1);
+    }
 }
@@ -1,16 +1,52 @@
 use crate::auth::AccountData;
-use error::GameLaunchError;
+use error::{GameLaunchError, OutOfDiskSpaceError};
 use ql_core::{
     GenericProgress, Instance, LaunchedProcess, err, flags::redact_sensitive_info, info,
 };
-use std::sync::{Arc, mpsc::Sender};
+use regex::Regex;
+use std::{
+    path::Path,
+    sync::{Arc, LazyLock, mpsc::Sender},
+};
 use tokio::sync::Mutex;
 
-pub(super) mod error;
+pub(crate) mod error;
 mod launcher;
-pub use launcher::GameLauncher;
+pub use launcher::{GameLauncher, cleanup_junk_files};
 use ql_core::json::GlobalSettings;
 
+/// Checks whether the disk containing `path` has at least
+/// `needed_bytes` of free space, to fail early with a clear error
+/// instead of halfway through a download.
+///
+/// `path` doesn't need to exist yet; the nearest
+/// existing parent folder is checked instead.
+/// If the free space can't be determined, this does nothing.
+///
+/// # Errors
+/// If there isn't enough space.
+pub async fn check_disk_space(path: &Path, needed_bytes: u64) -> Result<(), OutOfDiskSpaceError> {
+    let Some(path) = path.ancestors().find(|n| n.exists()) else {
+        return Ok(());
+    };
+    let path = path.to_owned();
+    let available = match tokio::task::spawn_blocking(move || fs2::available_space(path)).await {
+        Ok(Ok(n)) => n,
+        Ok(Err(err)) => {
+            err!(no_log, "Couldn't check available disk space: {err}");
+            return Ok(());
+        }
+        Err(_) => return Ok(()),
+    };
+    if available < needed_bytes {
+        return Err(OutOfDiskSpaceError {
+            available,
+            needed: needed_bytes,
+        });
+    }
+    Ok(())
+}
+
 /// Launches a Minecraft instance.
 ///
 /// # Arguments
@@ -88,9 +124,7 @@ pub async fn launch(
     );
     java_arguments.push(main_class);
 
-    info!("Java args: {java_arguments:?}\n");
-
-    print_censored_args(auth.as_ref(), &mut game_arguments);
+    print_censored_args(auth.as_ref(), &java_arguments, &mut game_arguments);
 
     let (mut command, path) = game_launcher
         .get_command(game_arguments, java_arguments)
@@ -108,15 +142,22 @@ pub async fn launch(
         child: Arc::new(Mutex::new(child)),
         instance: Instance::client(&instance_name),
         is_classic_server: false,
+        killed_by_user: Arc::default(),
     })
 }
 
-fn print_censored_args(auth: Option<&AccountData>, game_arguments: &mut Vec<String>) {
+fn print_censored_args(
+    auth: Option<&AccountData>,
+    java_arguments: &[String],
+    game_arguments: &mut Vec<String>,
+) {
     if !redact_sensitive_info() {
+        info!("Java args: {java_arguments:?}\n");
         info!("Game args: {:?}\n", game_arguments);
         return;
     }
 
+    info!("Java args: {:?}\n", censor_jvm_args(java_arguments));
     censor(game_arguments, "--clientId", |args| {
         censor(args, "--session", |args| {
             censor(args, "--accessToken", |args| {
@@ -128,7 +169,7 @@ fn print_censored_args(auth: Option<&AccountData>, game_arguments: &mut Vec<Stri
                             .and_then(|n| n.access_token.clone())
                             .unwrap_or_default(),
                         |args| {
-                            info!("Game args: {:?}\n", args);
+                            info!("Game args: {:?}\n", censor_jvm_args(args));
                         },
                     );
                 });
@@ -169,6 +210,21 @@ fn censor_string<F: FnOnce(&mut Vec<String>)>(vec: &[String], argument: &str, co
     code(&mut new);
 }
 
+/// Matches `=<value>` at the end of an argument, where
+/// the value looks like a token (long hex string or a JWT).
+static TOKEN_VALUE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"=(?:[0-9a-fA-F-]{32,}|[\w-]{10,}\.[\w-]{10,}\.[\w-]{10,})$").unwrap()
+});
+
+/// Redacts tokens embedded in arguments like
+/// `-Dminecraft.accessToken=<value>`, which
+/// some mod loaders use.
+fn censor_jvm_args(args: &[String]) -> Vec<String> {
+    args.iter()
+        .map(|arg| TOKEN_VALUE_REGEX.replace(arg, "=[REDACTED]").into_owned())
+        .collect()
+}
+
 fn replace_var(string: &mut String, var: &str, value: &str) {
     *string = string.replace(&format!("${{{var}}}"), value);
 }
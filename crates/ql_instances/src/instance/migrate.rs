@@ -13,13 +13,35 @@ use crate::download::GameDownloader;
 use super::launch::{GameLauncher, error::GameLaunchError};
 
 impl GameLauncher {
+    /// Upgrades instances created by older versions of
+    /// QuantumLauncher to the current format. Called
+    /// before every launch, so it must be cheap when
+    /// there's nothing to do.
+    ///
+    /// The launcher version that last touched the instance
+    /// is read from `launcher_version.txt` (missing means v0.1),
+    /// and then bumped to the current version.
+    ///
+    /// # Migrations
+    /// - **Natives** (before v0.3): native libraries weren't
+    ///   extracted, so they're extracted from the already
+    ///   downloaded library jars.
+    /// - **Forge classpath** (v0.4 and before): `forge/classpath.txt`
+    ///   used absolute paths, which broke on renaming instances.
+    ///   They're rewritten to be relative (`../forge/libraries/...`),
+    ///   keeping the originals in `classpath.txt.bak`
+    ///   and `classpath.txt.2.bak`.
+    /// - **authlib-injector** (v0.5 and before): the old
+    ///   downloaded jar is deleted so a newer one gets downloaded.
+    ///
+    /// Old asset locations (`instances/NAME/assets` and
+    /// `assets/<index id>`) are migrated separately when
+    /// the game arguments are filled in.
     pub async fn migrate_old_instances(&self) -> Result<(), GameLaunchError> {
-        self.cleanup_junk_files().await?;
-
         let version = self.migrate_get_version().await?;
 
         self.migrate_natives(&version).await?;
-        self.migrate_classpath_to_relative(&version).await?;
+        migrate_forge_classpath(&self.get_forge_dir(), &version).await?;
 
         if version <= ver(0, 5, 0) {
             // Force it to download the new version (1.2.7),
@@ -73,62 +95,6 @@ impl GameLauncher {
         Ok(())
     }
 
-    async fn migrate_classpath_to_relative(
-        &self,
-        version: &semver::Version,
-    ) -> Result<(), GameLaunchError> {
-        let v0_4_0 = ver(0, 4, 0);
-
-        let c_path = self.instance_dir.join("forge/classpath.txt");
-        if !exists(&c_path).await {
-            return Ok(()); // Forge isn't installed
-        }
-
-        if version < &v0_4_0 {
-            info!("Migrating Forge Classpath");
-            let classpath = tokio::fs::read_to_string(&c_path).await.path(&c_path)?;
-
-            let new_classpath = classpath
-                .split(':')
-                .map(|item| {
-                    // migrate the absolute paths to relative paths, to fix renaming instances
-                    if let Some(index) = item.find("forge/libraries") {
-                        let substring = &item[index..];
-                        format!("../{substring}")
-                    } else {
-                        item.to_string() // Or handle the case where the substring isn't found
-                    }
-                })
-                .collect::<Vec<String>>()
-                .join(":");
-
-            tokio::fs::write(&c_path, &new_classpath)
-                .await
-                .path(&c_path)?;
-
-            let bak_path = self.instance_dir.join("forge/classpath.txt.bak");
-            tokio::fs::write(&bak_path, &classpath)
-                .await
-                .path(&bak_path)?;
-        }
-
-        if version <= &v0_4_0 {
-            let classpath = tokio::fs::read_to_string(&c_path).await.path(&c_path)?;
-
-            let new_classpath = classpath_v0_3_1_to_v0_4(&classpath);
-            tokio::fs::write(&c_path, &new_classpath)
-                .await
-                .path(&c_path)?;
-
-            let bak_path = self.instance_dir.join("forge/classpath.txt.2.bak");
-            tokio::fs::write(&bak_path, &classpath)
-                .await
-                .path(&bak_path)?;
-        }
-
-        Ok(())
-    }
-
     async fn migrate_download_missing_native_libs(&self) -> Result<(), GameLaunchError> {
         info!("Downloading missing native libraries");
 
@@ -169,25 +135,63 @@ impl GameLauncher {
     /// What are `classpath_entries`? Well,
     /// see the comment in [`GameLauncher::get_class_path`]
     /// for more info.
+    ///
+    /// Called by [`GameLauncher::get_class_path`] when
+    /// `forge/clean_classpath.txt` doesn't exist (the Forge install
+    /// was made by an older launcher version). The raw
+    /// `forge/classpath.txt` (`forge_classpath`) is turned into
+    /// a list of library names without paths and versions
+    /// (see [`get_forge_clean_classpath`]), which are added to
+    /// `classpath_entries` and saved to `classpath_entries_path`
+    /// so this only happens once.
     pub async fn migrate_create_forge_clean_classpath(
         &self,
         forge_classpath: String,
         classpath_entries: &mut HashSet<String>,
         classpath_entries_path: PathBuf,
     ) -> Result<(), GameLaunchError> {
-        let forge_libs_dir = self.instance_dir.join("forge/libraries");
+        let forge_libs_dir = self.get_forge_dir().join("libraries");
         let forge_libs_dir = forge_libs_dir
             .to_str()
             .ok_or(GameLaunchError::PathBufToString(forge_libs_dir.clone()))?;
         let mut temp_forge_classpath_entries = String::new();
 
-        for entry in forge_classpath
-            .split(CLASSPATH_SEPARATOR)
-            .filter(|n| n.split_whitespace().any(|n| !n.is_empty()))
-        {
+        for entry in get_forge_clean_classpath(&forge_classpath, forge_libs_dir)? {
+            temp_forge_classpath_entries.push_str(&entry);
+            temp_forge_classpath_entries.push('\n');
+            classpath_entries.insert(entry);
+        }
+        tokio::fs::write(&classpath_entries_path, temp_forge_classpath_entries)
+            .await
+            .path(classpath_entries_path)?;
+        Ok(())
+    }
+}
+
+/// Turns the contents of `forge/classpath.txt` into the
+/// contents of `forge/clean_classpath.txt`: library names
+/// without their paths and versions.
+///
+/// Entries may either be absolute (starting with `forge_libs_dir`)
+/// or relative (`../forge/libraries/...`).
+///
+/// ```txt
+/// ../forge/libraries/net/minecraftforge/forge/1.21.1-52.0.28/forge-1.21.1-52.0.28-universal.jar
+/// -> net.minecraftforge:forge
+/// ```
+fn get_forge_clean_classpath(
+    forge_classpath: &str,
+    forge_libs_dir: &str,
+) -> Result<Vec<String>, GameLaunchError> {
+    forge_classpath
+        .split(CLASSPATH_SEPARATOR)
+        .filter(|n| n.split_whitespace().any(|n| !n.is_empty()))
+        .map(|entry| {
             // /net/minecraftforge/forge/1.21.1-52.0.28/forge-1.21.1-52.0.28-universal.jar
             let entry = entry
+                .trim()
                 .strip_prefix(forge_libs_dir)
+                .or_else(|| entry.trim().strip_prefix("../forge/libraries"))
                 .ok_or(GameLaunchError::ForgeInstallUpgradeStripPrefixError)?;
 
             // /.net.minecraftforge:forge
@@ -195,17 +199,67 @@ impl GameLauncher {
                 .ok_or(GameLaunchError::ForgeInstallUpgradeTransformPathError)?;
 
             // net.minecraftforge:forge
-            let entry = &entry[2..];
+            Ok(entry[2..].to_owned())
+        })
+        .collect()
+}
 
-            classpath_entries.insert(entry.to_owned());
-            temp_forge_classpath_entries.push_str(entry);
-            temp_forge_classpath_entries.push('\n');
-        }
-        tokio::fs::write(&classpath_entries_path, temp_forge_classpath_entries)
+/// Rewrites the absolute paths in `forge/classpath.txt`
+/// (from v0.4 and before) to paths relative to the instance.
+async fn migrate_forge_classpath(
+    forge_dir: &Path,
+    version: &semver::Version,
+) -> Result<(), GameLaunchError> {
+    let v0_4_0 = ver(0, 4, 0);
+
+    let c_path = forge_dir.join("classpath.txt");
+    if !exists(&c_path).await {
+        return Ok(()); // Forge isn't installed
+    }
+
+    if version < &v0_4_0 {
+        info!("Migrating Forge Classpath");
+        let classpath = tokio::fs::read_to_string(&c_path).await.path(&c_path)?;
+
+        let new_classpath = classpath
+            .split(':')
+            .map(|item| {
+                // migrate the absolute paths to relative paths, to fix renaming instances
+                if let Some(index) = item.find("forge/libraries") {
+                    let substring = &item[index..];
+                    format!("../{substring}")
+                } else {
+                    item.to_string() // Or handle the case where the substring isn't found
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(":");
+
+        tokio::fs::write(&c_path, &new_classpath)
             .await
-            .path(classpath_entries_path)?;
-        Ok(())
+            .path(&c_path)?;
+
+        let bak_path = forge_dir.join("classpath.txt.bak");
+        tokio::fs::write(&bak_path, &classpath)
+            .await
+            .path(&bak_path)?;
+    }
+
+    if version <= &v0_4_0 {
+        let classpath = tokio::fs::read_to_string(&c_path).await.path(&c_path)?;
+
+        let new_classpath = classpath_v0_3_1_to_v0_4(&classpath);
+        tokio::fs::write(&c_path, &new_classpath)
+            .await
+            .path(&c_path)?;
+
+        let bak_path = forge_dir.join("classpath.txt.2.bak");
+        tokio::fs::write(&bak_path, &classpath)
+            .await
+            .path(&bak_path)?;
     }
+
+    Ok(())
 }
 
 /// Converts a path string into the desired format:
@@ -262,3 +316,71 @@ const fn ver(major: u64, minor: u64, patch: u64) -> semver::Version {
         build: semver::BuildMetadata::EMPTY,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn forge_clean_classpath_from_classpath() {
+        let forge_libs_dir = "/home/user/QuantumLauncher/instances/Test/forge/libraries";
+        let classpath = [
+            format!("{forge_libs_dir}/net/minecraftforge/forge/1.21.1-52.0.28/forge-1.21.1-52.0.28-universal.jar"),
+            "../forge/libraries/org/ow2/asm/asm/9.7/asm-9.7.jar".to_owned(),
+            "../forge/libraries/cpw/mods/securejarhandler/3.0.8/securejarhandler-3.0.8.jar"
+                .to_owned(),
+            String::new(),
+        ]
+        .join(&CLASSPATH_SEPARATOR.to_string());
+
+        assert_eq!(
+            get_forge_clean_classpath(&classpath, forge_libs_dir).unwrap(),
+            [
+                "net.minecraftforge:forge",
+                "org.ow2.asm:asm",
+                "cpw.mods:securejarhandler",
+            ]
+        );
+        assert!(get_forge_clean_classpath("/somewhere/else/a/b/c.jar", forge_libs_dir).is_err());
+    }
+
+    #[tokio::test]
+    async fn forge_classpath_made_relative() {
+        let dir = tempfile::tempdir().unwrap();
+        let instance_dir = dir.path();
+        let forge_dir = instance_dir.join("forge");
+        tokio::fs::create_dir_all(&forge_dir).await.unwrap();
+
+        // Classpath as written by v0.3.0 (absolute paths)
+        let old_classpath = "/home/user/QuantumLauncher/instances/Old/forge/libraries/a/a.jar:\
+            /home/user/QuantumLauncher/instances/Old/forge/libraries/b/b.jar";
+        tokio::fs::write(forge_dir.join("classpath.txt"), old_classpath)
+            .await
+            .unwrap();
+
+        migrate_forge_classpath(&forge_dir, &ver(0, 3, 0))
+            .await
+            .unwrap();
+
+        let classpath = tokio::fs::read_to_string(forge_dir.join("classpath.txt"))
+            .await
+            .unwrap();
+        assert_eq!(
+            classpath,
+            "../forge/libraries/a/a.jar:../forge/libraries/b/b.jar"
+        );
+        let backup = tokio::fs::read_to_string(forge_dir.join("classpath.txt.bak"))
+            .await
+            .unwrap();
+        assert_eq!(backup, old_classpath);
+
+        // Already up to date: left untouched
+        migrate_forge_classpath(&forge_dir, &LAUNCHER_VERSION)
+            .await
+            .unwrap();
+        let unchanged = tokio::fs::read_to_string(forge_dir.join("classpath.txt"))
+            .await
+            .unwrap();
+        assert_eq!(unchanged, classpath);
+    }
+}
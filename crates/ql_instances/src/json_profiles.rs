@@ -2,10 +2,29 @@ use std::collections::BTreeMap;
 
 use serde::Serialize;
 
+/// Files that the official launcher stores profiles in.
+///
+/// The Microsoft Store version of the launcher uses
+/// `launcher_profiles_microsoft_store.json`, and some
+/// Forge installers check for that one instead.
+pub const PROFILE_FILE_NAMES: [&str; 2] = [
+    "launcher_profiles.json",
+    "launcher_profiles_microsoft_store.json",
+];
+
 /// Represents the `launcher_profiles.json` file.
 ///
 /// It's not needed for the game to run, but some
 /// loader installers depend on it so it's included.
+///
+/// # Format requirements
+/// For Mojang's launcher (and the Forge installer,
+/// which adds its own profile) to accept it:
+/// - `profiles` must be an object (may be empty)
+/// - `settings` must be an object
+/// - `version` should be `3`, the current profile format
+///
+/// Everything else is optional and may be `null`.
 #[derive(Serialize)]
 #[allow(non_snake_case)]
 pub struct ProfileJson {
@@ -107,7 +126,22 @@ impl Default for ProfileJson {
             analyticsToken: None,
             analyticsFailcount: None,
             selectedUser: None,
-            version: None,
+            version: Some(3),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_has_required_fields() {
+        let json = serde_json::to_string(&ProfileJson::default()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        assert!(json["profiles"].is_object());
+        assert!(json["settings"].is_object());
+        assert_eq!(json["version"], 3);
+    }
+}
@@ -56,6 +56,12 @@ mod instance;
 mod json_profiles;
 
 pub use download::{DownloadError, create_instance, repeat_stage};
-pub use instance::{launch::launch, list_versions::list_versions, notes};
+pub use instance::{
+    launch::{check_disk_space, cleanup_junk_files, error::OutOfDiskSpaceError, launch},
+    list_versions::list_versions,
+    notes,
+};
 pub use ql_core::jarmod;
-pub use ql_java_handler::delete_java_installs;
+pub use ql_java_handler::{
+    delete_java_installs, get_available_versions as get_available_java_versions,
+};
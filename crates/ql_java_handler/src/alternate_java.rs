@@ -10,7 +10,7 @@ use std::{
 
 use cfg_if::cfg_if;
 use owo_colors::OwoColorize;
-use ql_core::{GenericProgress, JavaVersion, file_utils, pt};
+use ql_core::{GenericProgress, IntoIoError, JavaVersion, file_utils, pt};
 use serde::Deserialize;
 
 use crate::{JavaInstallError, extract_tar_gz, send_progress};
@@ -21,12 +21,23 @@ pub(crate) async fn install(
     install_dir: &Path,
 ) -> Result<(), JavaInstallError> {
     let Some(url) = get_url(version).await? else {
-        return Err(JavaInstallError::UnsupportedPlatform);
+        return Err(if crate::is_musl() {
+            JavaInstallError::MuslLibcUnsupported
+        } else {
+            JavaInstallError::UnsupportedPlatform
+        });
     };
 
     progress(sender, "Getting compressed archive", 0);
     pt!("URL: {}", url.bright_black());
-    let file_bytes = file_utils::download_file_to_bytes(&url, false).await?;
+    // Kept outside `install_dir` so an interrupted
+    // download can be resumed next time
+    let archive_path = install_dir.with_extension("download");
+    file_utils::download_with_resume(&url, &archive_path, sender).await?;
+    let file_bytes = tokio::fs::read(&archive_path).await.path(&archive_path)?;
+    tokio::fs::remove_file(&archive_path)
+        .await
+        .path(&archive_path)?;
 
     progress(sender, "Extracting archive", 1);
     if url.ends_with("tar.gz") {
@@ -57,7 +68,7 @@ async fn get_url(mut version: JavaVersion) -> Result<Option<String>, JavaInstall
     if let JavaVersion::Java8 = version {
         return Ok(Some("https://github.com/Mrmayman/get-jdk/releases/download/java8-1/jdk-8u452-freebsd-x64.tar.gz".to_owned()));
     }
-    if let JavaVersion::Java21 = version {
+    if let (JavaVersion::Java21, false) = (version, crate::is_musl()) {
         if cfg!(any(
             feature = "simulate_linux_arm32",
             all(target_os = "linux", target_arch = "arm")
@@ -122,7 +133,7 @@ fn get_os() -> &'static str {
     } else if #[cfg(feature = "simulate_macos_arm64")] {
         return "macos"
     } else if #[cfg(all(target_os = "linux", target_env = "gnu"))] {
-        return "linux-glibc";
+        return if crate::is_musl() { "linux-musl" } else { "linux-glibc" };
     } else if #[cfg(all(target_os = "linux", target_env = "musl"))] {
         return "linux-musl";
     });
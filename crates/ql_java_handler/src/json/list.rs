@@ -49,6 +49,20 @@ impl JavaListJson {
         return None;
     }
 
+    /// Java versions that Mojang provides
+    /// for the current platform.
+    #[must_use]
+    pub fn get_available_versions(&self) -> Vec<JavaVersion> {
+        let Some(java_list) = self.get_platform() else {
+            return Vec::new();
+        };
+        JavaVersion::ALL
+            .iter()
+            .copied()
+            .filter(|n| read_ver_from_list(*n, java_list).is_some())
+            .collect()
+    }
+
     pub fn get_url(&self, mut version: JavaVersion) -> Option<String> {
         let java_list = self.get_platform()?;
         let mut fetched = read_ver_from_list(version, java_list);
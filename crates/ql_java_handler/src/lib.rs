@@ -56,6 +56,8 @@ use json::{
     list::JavaListJson,
 };
 use owo_colors::OwoColorize;
+#[cfg(target_os = "linux")]
+use std::sync::LazyLock;
 use std::{
     env::consts::ARCH,
     path::{Path, PathBuf},
@@ -220,8 +222,13 @@ async fn install_java(
 
     send_progress(java_install_progress_sender, GenericProgress::default());
 
-    let java_list_json = JavaListJson::download().await?;
-    let Some(java_files_url) = java_list_json.get_url(version) else {
+    // Mojang's Java is linked against glibc, and crashes on musl
+    let java_files_url = if is_musl() {
+        None
+    } else {
+        JavaListJson::download().await?.get_url(version)
+    };
+    let Some(java_files_url) = java_files_url else {
         // Mojang doesn't officially provide java for som platforms.
         // In that case, fetch from alternate sources.
         alternate_java::install(version, java_install_progress_sender, &install_dir).await?;
@@ -284,6 +291,29 @@ async fn get_install_dir(version: JavaVersion) -> Result<PathBuf, JavaInstallErr
     Ok(install_dir)
 }
 
+/// Whether the system uses musl libc (like Alpine Linux),
+/// detected by looking for `/lib/libc.musl-*`.
+///
+/// This is checked at runtime, because a launcher built
+/// against glibc might still be running on a musl system
+/// (eg: through `gcompat`).
+#[cfg(target_os = "linux")]
+pub(crate) fn is_musl() -> bool {
+    static IS_MUSL: LazyLock<bool> = LazyLock::new(|| {
+        cfg!(target_env = "musl")
+            || std::fs::read_dir("/lib").is_ok_and(|dir| {
+                dir.filter_map(Result::ok)
+                    .any(|n| n.file_name().to_string_lossy().starts_with("libc.musl-"))
+            })
+    });
+    *IS_MUSL
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn is_musl() -> bool {
+    false
+}
+
 fn send_progress(sender: Option<&Sender<GenericProgress>>, progress: GenericProgress) {
     if let Some(sender) = sender {
         _ = sender.send(progress);
@@ -409,6 +439,10 @@ at: {path:?}
 
     #[error("({OS_NAME} {ARCH})\n{UNSUPPORTED_MESSAGE}")]
     UnsupportedPlatform,
+    #[error(
+        "({OS_NAME} {ARCH}, musl libc)\nNo Java build for musl-based systems (like Alpine Linux) is available for this version.\n\nInstall Java through your package manager and set the executable path in the Instance → Edit tab"
+    )]
+    MuslLibcUnsupported,
 
     #[error("{ERR_PREF1}{OS_NAME} {ARCH}):\nzip extract error:\n{0}")]
     ZipExtract(#[from] zip::result::ZipError),
@@ -420,6 +454,21 @@ at: {path:?}
     UnknownExtension(String),
 }
 
+/// Returns the Java versions that can be downloaded from Mojang
+/// for the current platform.
+///
+/// Empty if Mojang doesn't provide Java here
+/// (in which case alternate sources are used when installing).
+///
+/// # Errors
+/// If the Java list couldn't be downloaded or parsed.
+pub async fn get_available_versions() -> Result<Vec<JavaVersion>, JsonDownloadError> {
+    if is_musl() {
+        return Ok(Vec::new());
+    }
+    Ok(JavaListJson::download().await?.get_available_versions())
+}
+
 /// Deletes all the auto-installed Java installations.
 ///
 /// They are stored in `QuantumLauncher/java_installs/`
@@ -27,5 +27,5 @@ mod rate_limiter;
 /// Mod manager integrated with Modrinth and Curseforge.
 pub mod store;
 
-pub use presets::{Preset, PresetOutput};
+pub use presets::{Preset, PresetDiff, PresetOutput};
 pub use store::add_files;
@@ -23,6 +23,8 @@ pub enum FabricInstallError {
     ZipEntryReadError(std::io::Error, String),
     #[error("{FABRIC_INSTALL_ERR_PREFIX}no compatible version found for your instance")]
     NoVersionFound,
+    #[error("{FABRIC_INSTALL_ERR_PREFIX}the loader JSON doesn't specify a main class")]
+    NoMainClass,
 }
 
 impl_3_errs_jri!(FabricInstallError, Json, RequestError, Io);
@@ -211,3 +211,102 @@ fn split_string(s: &str) -> Vec<String> {
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use zip::ZipArchive;
+
+    fn write_jar(path: &Path, entries: &[(&str, &str)]) {
+        let mut zip = ZipWriter::new(File::create(path).unwrap());
+        for (name, content) in entries {
+            zip.start_file(*name, FileOptions::<()>::default()).unwrap();
+            zip.write_all(content.as_bytes()).unwrap();
+        }
+        zip.finish().unwrap();
+    }
+
+    fn read_entry(jar: &mut ZipArchive<File>, name: &str) -> String {
+        let mut content = String::new();
+        jar.by_name(name)
+            .unwrap()
+            .read_to_string(&mut content)
+            .unwrap();
+        content
+    }
+
+    #[tokio::test]
+    async fn shaded_launch_jar() {
+        let dir = tempfile::tempdir().unwrap();
+        let lib_a = dir.path().join("a.jar");
+        let lib_b = dir.path().join("b.jar");
+        write_jar(
+            &lib_a,
+            &[
+                ("a/A.class", "a"),
+                ("META-INF/services/x.Service", "a.Impl\n"),
+                ("META-INF/A.SF", "signature"),
+            ],
+        );
+        write_jar(
+            &lib_b,
+            &[
+                ("b/B.class", "b"),
+                ("a/A.class", "duplicate"),
+                ("META-INF/services/x.Service", "b.Impl # comment\n"),
+            ],
+        );
+
+        let launch_jar = dir.path().join("fabric-server-launch.jar");
+        make_launch_jar(
+            &launch_jar,
+            dir.path(),
+            "net.fabricmc.loader.Main",
+            &[lib_a, lib_b],
+            true,
+        )
+        .await
+        .unwrap();
+
+        let mut jar = ZipArchive::new(File::open(&launch_jar).unwrap()).unwrap();
+        let manifest = read_entry(&mut jar, MANIFEST_PATH);
+        assert!(manifest.contains(&format!("Main-Class: {MAIN_CLASS_MANIFEST}")));
+        assert!(!manifest.contains("Class-Path"));
+        assert_eq!(
+            read_entry(&mut jar, "fabric-server-launch.properties"),
+            "launch.mainClass=net.fabricmc.loader.Main\n"
+        );
+
+        // First library wins on duplicates, signatures are dropped
+        assert_eq!(read_entry(&mut jar, "a/A.class"), "a");
+        assert_eq!(read_entry(&mut jar, "b/B.class"), "b");
+        assert!(jar.by_name("META-INF/A.SF").is_err());
+
+        let services = read_entry(&mut jar, "META-INF/services/x.Service");
+        let mut services: Vec<&str> = services.lines().collect();
+        services.sort_unstable();
+        assert_eq!(services, ["a.Impl", "b.Impl"]);
+    }
+
+    #[tokio::test]
+    async fn unshaded_launch_jar_has_relative_class_path() {
+        let dir = tempfile::tempdir().unwrap();
+        // Libraries aren't read when not shading
+        let libraries = [
+            dir.path().join("libraries").join("a.jar"),
+            dir.path().join("libraries").join("b.jar"),
+        ];
+
+        let launch_jar = dir.path().join("fabric-server-launch.jar");
+        make_launch_jar(&launch_jar, dir.path(), "Main", &libraries, false)
+            .await
+            .unwrap();
+
+        let mut jar = ZipArchive::new(File::open(&launch_jar).unwrap()).unwrap();
+        let manifest = read_entry(&mut jar, MANIFEST_PATH);
+        assert!(manifest.contains("Class-Path: libraries/a.jar libraries/b.jar\n"));
+        assert!(manifest.contains(&format!("Main-Class: {MAIN_CLASS_MANIFEST}")));
+        assert_eq!(jar.len(), 2);
+    }
+}
@@ -1,21 +1,22 @@
 use std::{
+    io::Read,
     path::{Path, PathBuf},
     sync::{Mutex, mpsc::Sender},
 };
 
 use ql_core::{
     GenericProgress, Instance, InstanceKind, IntoIoError, IntoJsonError, LAUNCHER_DIR, Loader,
-    do_jobs, download,
+    do_jobs, download, err,
     file_utils::exists,
     info,
-    json::{FabricJSON, V_1_12_2, VersionDetails, instance_config::ModTypeInfo},
+    json::{FabricJSON, Manifest, V_1_12_2, VersionDetails, instance_config::ModTypeInfo},
     pt,
 };
 use version_compare::compare_versions;
 
 use crate::loaders::fabric::version_list::get_latest_cursed_legacy_commit;
 
-use super::change_instance_type;
+use super::{change_instance_type, try_downloading_from_urls};
 
 mod error;
 pub use error::FabricInstallError;
@@ -50,7 +51,7 @@ pub async fn install_server(
         .await
         .path(&libraries_dir)?;
 
-    let version_json = VersionDetails::load_from_path(&server_dir).await?;
+    let version_json = load_server_version_json(&server_dir).await?;
     let json: FabricJSON = {
         let json = if let BackendType::CursedLegacy = backend {
             CURSED_LEGACY_JSON.replace("INSERT_COMMIT", &get_latest_cursed_legacy_commit().await?)
@@ -61,6 +62,9 @@ pub async fn install_server(
         tokio::fs::write(&json_path, &json).await.path(json_path)?;
         serde_json::from_str(&json).json(json)?
     };
+    let main_class = json
+        .get_server_main_class()
+        .ok_or(FabricInstallError::NoMainClass)?;
 
     let number_of_libraries = json.libraries.len() + 1;
     let i = Mutex::new(0);
@@ -90,7 +94,7 @@ pub async fn install_server(
     make_launch_jar::make_launch_jar(
         &launch_jar,
         &server_dir,
-        json.mainClassServer.as_deref().unwrap_or(&json.mainClass),
+        main_class,
         &library_files,
         shade_libraries,
     )
@@ -120,6 +124,53 @@ pub async fn install_server(
     Ok(())
 }
 
+/// Loads the server's `details.json`.
+///
+/// If that fails (eg: the server was created without a full
+/// version JSON), a minimal [`VersionDetails`] is made from
+/// the `version.json` inside `server.jar` (1.14 and above),
+/// with the release date taken from the version [`Manifest`].
+///
+/// # Errors
+/// Returns the original `details.json` error if the version
+/// can't be found in either `server.jar` or the manifest
+/// (without a release date, the version checks would be wrong).
+async fn load_server_version_json(server_dir: &Path) -> Result<VersionDetails, FabricInstallError> {
+    let err = match VersionDetails::load_from_path(server_dir).await {
+        Ok(version_json) => return Ok(version_json),
+        Err(err) => err,
+    };
+
+    let Some(id) = read_server_jar_version_id(&server_dir.join("server.jar")) else {
+        return Err(err.into());
+    };
+    let manifest = Manifest::download().await?;
+    let Some(version) = manifest.find_name(&id) else {
+        err!("Server version {id} (from server.jar) isn't in the version manifest");
+        return Err(err.into());
+    };
+
+    err!(
+        no_log,
+        "Couldn't load server details.json, using server.jar version ({id})\n{err}"
+    );
+    let mut version_json = VersionDetails::default();
+    version_json.id = id;
+    version_json.releaseTime = version.releaseTime.clone();
+    Ok(version_json)
+}
+
+fn read_server_jar_version_id(jar_path: &Path) -> Option<String> {
+    let jar = std::fs::File::open(jar_path).ok()?;
+    let mut zip = zip::ZipArchive::new(jar).ok()?;
+    let mut file = zip.by_name("version.json").ok()?;
+    let mut json = String::new();
+    file.read_to_string(&mut json).ok()?;
+
+    let json: serde_json::Value = serde_json::from_str(&json).ok()?;
+    Some(json.get("id")?.as_str()?.to_owned())
+}
+
 async fn download_library(
     library: &ql_core::json::fabric::Library,
     libraries_dir: &Path,
@@ -175,6 +226,9 @@ pub async fn install_client(
         tokio::fs::write(&json_path, &json).await.path(json_path)?;
         serde_json::from_str(&json).json(json)?
     };
+    if json.get_client_main_class().is_none() {
+        return Err(FabricInstallError::NoMainClass);
+    }
 
     info!("Started installing {backend}: {game_version}, {loader_version}");
     if let Some(progress) = &progress {
@@ -237,20 +291,19 @@ async fn get_fabric_json(
         } else {
             "fabric"
         };
-        let url1 = format!(
-            "https://meta.ornithemc.net/v3/versions/{fq}-loader/{game_version}/{loader_version}/{implementation}/json"
-        );
-        let url2 = format!(
-            "https://meta.ornithemc.net/v3/versions/{fq}-loader/{game_version}-{implementation_kind}/{loader_version}/{implementation}/json"
-        );
-
-        match download(&url1).string().await {
-            Ok(n) => n,
-            Err(err) => match download(&url2).string().await {
-                Ok(n) => n,
-                Err(_) => Err(err)?,
-            },
-        }
+        let json = try_downloading_from_urls(&[
+            &format!(
+                "https://meta.ornithemc.net/v3/versions/{fq}-loader/{game_version}/{loader_version}/{implementation}/json"
+            ),
+            &format!(
+                "https://meta.ornithemc.net/v3/versions/{fq}-loader/{game_version}-{implementation_kind}/{loader_version}/{implementation}/json"
+            ),
+            &format!(
+                "https://ornithemc.net/meta/v3/versions/{fq}-loader/{game_version}-{implementation_kind}/{loader_version}/{implementation}/json"
+            ),
+        ])
+        .await?;
+        String::from_utf8_lossy(&json).into_owned()
     } else {
         download(&format!(
             "{}/versions/loader/{game_version}/{loader_version}/{implementation}/json",
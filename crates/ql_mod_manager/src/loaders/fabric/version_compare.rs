@@ -91,4 +91,103 @@ mod tests {
         assert_eq!(compare_versions("0.1.1-alpha", "0.1.1"), Ordering::Less);
         assert_eq!(compare_versions("0.1.1", "0.1.1-alpha"), Ordering::Greater);
     }
+
+    #[test]
+    fn equal_versions() {
+        assert_eq!(compare_versions("0.14.21", "0.14.21"), Ordering::Equal);
+        assert_eq!(
+            compare_versions("0.14.0-rc5", "0.14.0-rc5"),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn build_metadata_is_ignored() {
+        assert_eq!(
+            compare_versions("1.0.0+build.1", "1.0.0+build.2"),
+            Ordering::Equal
+        );
+        assert_eq!(
+            compare_versions("0.14.0-rc1+build.5", "0.14.0-rc1"),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn minor_ordering() {
+        assert_eq!(compare_versions("0.13.3", "0.14.0"), Ordering::Less);
+        assert_eq!(compare_versions("1.0.0", "0.99.99"), Ordering::Greater);
+    }
+
+    #[test]
+    fn patch_ordering() {
+        assert_eq!(compare_versions("0.14.21", "0.14.22"), Ordering::Less);
+        assert_eq!(compare_versions("0.14.22", "0.14.21"), Ordering::Greater);
+    }
+
+    #[test]
+    fn numeric_not_lexicographic() {
+        assert_eq!(compare_versions("0.12.5", "0.12.10"), Ordering::Less);
+        assert_eq!(compare_versions("0.9.0", "0.10.0"), Ordering::Less);
+        assert_eq!(compare_versions("0.100.0", "0.20.0"), Ordering::Greater);
+    }
+
+    #[test]
+    fn pre_release_before_release() {
+        assert_eq!(compare_versions("0.14.0-rc5", "0.14.0"), Ordering::Less);
+        assert_eq!(compare_versions("0.14.0", "0.14.0-rc5"), Ordering::Greater);
+        // The version core still takes priority
+        assert_eq!(compare_versions("0.14.0-rc5", "0.13.3"), Ordering::Greater);
+    }
+
+    #[test]
+    fn pre_release_ordering() {
+        assert_eq!(compare_versions("0.14.0-rc5", "0.14.0-rc6"), Ordering::Less);
+        assert_eq!(
+            compare_versions("0.14.0-alpha", "0.14.0-beta"),
+            Ordering::Less
+        );
+        assert_eq!(
+            compare_versions("0.14.0-beta.2", "0.14.0-beta.10"),
+            Ordering::Less
+        );
+        // Numeric parts come before text ones
+        assert_eq!(compare_versions("1.0.0-1", "1.0.0-alpha"), Ordering::Less);
+    }
+
+    #[test]
+    fn four_components() {
+        assert_eq!(compare_versions("0.4.8.12", "0.4.8.13"), Ordering::Less);
+        assert_eq!(compare_versions("0.4.8", "0.4.8.1"), Ordering::Less);
+        assert_eq!(compare_versions("1.2.3.4", "1.2.3"), Ordering::Greater);
+        assert_eq!(compare_versions("1.2.4", "1.2.3.9"), Ordering::Greater);
+    }
+
+    #[test]
+    fn not_a_version_falls_back_to_string_order() {
+        assert_eq!(compare_versions("abc", "abd"), Ordering::Less);
+        assert_eq!(compare_versions("abc", "abc"), Ordering::Equal);
+    }
+
+    #[test]
+    fn ordering_is_antisymmetric() {
+        let versions = [
+            "0.12.5",
+            "0.12.10",
+            "0.14.0-rc5",
+            "0.14.0",
+            "0.14.0.1",
+            "0.14.0-beta.2",
+            "1.0.0+build.1",
+        ];
+        for a in versions {
+            for b in versions {
+                assert_eq!(
+                    compare_versions(a, b),
+                    compare_versions(b, a).reverse(),
+                    "{a} vs {b}"
+                );
+            }
+        }
+    }
 }
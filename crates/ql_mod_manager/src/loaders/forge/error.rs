@@ -44,6 +44,10 @@ pub enum ForgeInstallError {
     ChronoTime(#[from] chrono::ParseError),
     #[error("NeoForge only supports Minecraft 1.20.2 and above, your version is outdated")]
     NeoForgeOutdatedMinecraft,
+    #[error(
+        "Forge is not available for this version ({0})\n\nForge only supports Minecraft 1.1 and above"
+    )]
+    UnsupportedMinecraftVersion(String),
 
     #[error("{FORGE_INSTALL_ERR_PREFIX}zip: {0}")]
     Zip(#[from] zip::result::ZipError),
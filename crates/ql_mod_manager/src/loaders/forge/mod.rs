@@ -1,12 +1,11 @@
-use error::Is404NotFound;
 use owo_colors::OwoColorize;
 use ql_core::{
     CLASSPATH_SEPARATOR, GenericProgress, Instance, InstanceKind, IntoIoError, IntoJsonError,
     IoError, Loader, Progress, do_jobs, download, err,
-    file_utils::{self, exists},
+    file_utils::exists,
     info,
     json::{
-        VersionDetails,
+        InstanceConfigJson, VersionDetails,
         forge::{JsonDetails, JsonDetailsLibrary, JsonInstallProfile, JsonVersions},
         instance_config::ModTypeInfo,
     },
@@ -15,6 +14,7 @@ use ql_core::{
 use ql_java_handler::{JAVA, JavaVersion, get_java_binary};
 use std::sync::Mutex;
 use std::{
+    collections::HashSet,
     fmt::Write,
     io::Cursor,
     path::{Path, PathBuf},
@@ -23,7 +23,9 @@ use std::{
 };
 use tokio::fs;
 
-use crate::loaders::{FORGE_INSTALLER_CLIENT, FORGE_INSTALLER_SERVER, change_instance_type};
+use crate::loaders::{
+    FORGE_INSTALLER_CLIENT, FORGE_INSTALLER_SERVER, change_instance_type, try_downloading_from_urls,
+};
 
 mod error;
 mod server;
@@ -31,6 +33,7 @@ pub use server::install_server;
 mod uninstall;
 
 pub use error::ForgeInstallError;
+pub(crate) use error::Is404NotFound;
 pub use uninstall::uninstall;
 
 struct ForgeInstaller {
@@ -65,11 +68,16 @@ impl ForgeInstaller {
         let forge_dir = if instance.is_server() {
             instance_dir.clone()
         } else {
-            get_forge_dir(&instance_dir).await?
+            get_forge_dir_for_instance(&instance).await?
         };
 
         let version_json = VersionDetails::load(&instance).await?;
         let minecraft_version = version_json.get_id();
+        if !version_json.supports_forge() {
+            return Err(ForgeInstallError::UnsupportedMinecraftVersion(
+                minecraft_version.to_owned(),
+            ));
+        }
 
         create_mods_dir(&instance_dir).await?;
 
@@ -127,7 +135,7 @@ impl ForgeInstaller {
         info!("Downloading Installer");
         self.send_progress(ForgeInstallProgress::P3DownloadingInstaller);
 
-        let installer_file = self.try_downloading_from_urls(&[
+        let installer_file = try_downloading_from_urls(&[
             &format!("https://files.minecraftforge.net/maven/net/minecraftforge/forge/{ver}/forge-{ver}-{file_type}.jar", ver = self.short_version),
             &format!("https://files.minecraftforge.net/maven/net/minecraftforge/forge/{ver}/forge-{ver}-{file_type}.jar", ver = self.norm_forge_version),
             &format!("https://files.minecraftforge.net/maven/net/minecraftforge/forge/{ver}/forge-{ver}-{file_type_flipped}.jar", ver = self.short_version),
@@ -154,28 +162,6 @@ impl ForgeInstaller {
         }
     }
 
-    async fn try_downloading_from_urls(&self, urls: &[&str]) -> Result<Vec<u8>, ForgeInstallError> {
-        let num_urls = urls.len();
-        for (i, url) in urls.iter().enumerate() {
-            let result = file_utils::download_file_to_bytes(url, false).await;
-
-            return match result {
-                Ok(file) => {
-                    pt!("{}: {}", "Url".underline(), url.bright_black());
-                    Ok(file)
-                }
-                Err(err) => {
-                    let is_last_url = i + 1 == num_urls;
-                    if err.is_not_found() && !is_last_url {
-                        continue;
-                    }
-                    Err(ForgeInstallError::Request(err))
-                }
-            };
-        }
-        panic!("Forge installer: Reached invalid state (while retrying downloads)")
-    }
-
     async fn run_installer_and_get_classpath(
         &self,
         installer_name: &str,
@@ -314,15 +300,51 @@ impl ForgeInstaller {
         ))
     }
 
-    async fn download_library(
-        &self,
-        library: JsonDetailsLibrary,
-        library_i: &Mutex<usize>,
-        num_libraries: usize,
+    /// Checks which of the given libraries need to be (re)downloaded,
+    /// i.e. their files are missing or corrupt (size doesn't match).
+    ///
+    /// Libraries without a known size are only checked for existence.
+    /// This avoids redownloading everything when repairing
+    /// (reinstalling) an existing Forge installation.
+    async fn verify_libraries(
+        libraries: &[JsonDetailsLibrary],
         libraries_dir: &Path,
-        classpath: &Mutex<String>,
+    ) -> Vec<JsonDetailsLibrary> {
+        let mut failing = Vec::new();
+        for library in libraries {
+            let parts: Vec<&str> = library.name.split(':').collect();
+            let (Some(class), Some(lib), Some(ver)) = (parts.first(), parts.get(1), parts.get(2))
+            else {
+                failing.push(library.clone());
+                continue;
+            };
+            let Ok((file, path)) = Self::get_filename_and_path(lib, ver, library, class) else {
+                failing.push(library.clone());
+                continue;
+            };
+
+            let dest = libraries_dir.join(&path).join(&file);
+            let is_intact = fs::metadata(&dest).await.is_ok_and(|metadata| {
+                metadata.is_file()
+                    && library.downloads.as_ref().map_or(metadata.len() > 0, |n| {
+                        metadata.len() == n.artifact.size as u64
+                    })
+            });
+            if !is_intact {
+                failing.push(library.clone());
+            }
+        }
+        failing
+    }
+
+    /// Adds the library to `clean_classpath` and returns its
+    /// `(file, path)`, or `None` if it's a built-in library
+    /// that shouldn't be downloaded or added to the classpath.
+    fn register_library(
+        &self,
+        library: &JsonDetailsLibrary,
         clean_classpath: &Mutex<String>,
-    ) -> Result<(), ForgeInstallError> {
+    ) -> Result<Option<(String, String)>, ForgeInstallError> {
         let parts: Vec<&str> = library.name.split(':').collect();
         let class = parts[0];
         let lib = parts[1];
@@ -330,12 +352,27 @@ impl ForgeInstaller {
 
         _ = writeln!(clean_classpath.lock().unwrap(), "{class}:{lib}");
 
-        let (file, path) = Self::get_filename_and_path(lib, ver, &library, class)?;
+        let (file, path) = Self::get_filename_and_path(lib, ver, library, class)?;
 
         if class == "net.minecraftforge" && lib == "forge" && self.major_version < 49 {
+            return Ok(None);
+        }
+        Ok(Some((file, path)))
+    }
+
+    async fn download_library(
+        &self,
+        library: JsonDetailsLibrary,
+        library_i: &Mutex<usize>,
+        num_libraries: usize,
+        libraries_dir: &Path,
+        classpath: &Mutex<String>,
+        clean_classpath: &Mutex<String>,
+    ) -> Result<(), ForgeInstallError> {
+        let Some((file, path)) = self.register_library(&library, clean_classpath)? else {
             pt!("(_/{num_libraries}): built-in forge library, skipping...");
             return Ok(());
-        }
+        };
 
         let url = if let Some(downloads) = &library.downloads {
             downloads.artifact.url.clone()
@@ -354,14 +391,12 @@ impl ForgeInstaller {
             .path(&lib_dir_path)?;
 
         let dest = lib_dir_path.join(&file);
-        if !exists(&dest).await {
-            let result = download(&url).path(&dest).await;
-            if result.is_not_found() {
-                err!("Error 404 not found. Skipping...");
-                return Ok(());
-            }
-            result?;
+        let result = download(&url).path(&dest).await;
+        if result.is_not_found() {
+            err!("Error 404 not found. Skipping...");
+            return Ok(());
         }
+        result?;
 
         {
             let mut i = library_i.lock().unwrap();
@@ -425,8 +460,18 @@ async fn get_forge_version(minecraft_version: &str) -> Result<String, ForgeInsta
     Ok(version)
 }
 
-async fn get_forge_dir(instance_dir: &Path) -> Result<PathBuf, ForgeInstallError> {
-    let forge_dir = instance_dir.join("forge");
+/// Gets (and creates if missing) the directory where
+/// Forge files of a client instance are stored.
+///
+/// This is `instance_dir/forge`, unless
+/// `forge_dir_override` is set in the instance's `config.json`.
+///
+/// # Errors
+/// - `config.json` couldn't be read or parsed
+/// - The directory couldn't be created
+pub async fn get_forge_dir_for_instance(instance: &Instance) -> Result<PathBuf, ForgeInstallError> {
+    let config = InstanceConfigJson::read(instance).await?;
+    let forge_dir = config.get_forge_dir(&instance.get_instance_path());
     fs::create_dir_all(&forge_dir).await.path(&forge_dir)?;
     Ok(forge_dir)
 }
@@ -536,9 +581,26 @@ pub async fn install_client(
         .into_iter()
         .filter(|library| !matches!(library.clientreq, Some(false)))
         .collect();
-    let num_libraries = libs.len();
+
+    let failing = ForgeInstaller::verify_libraries(&libs, &libraries_dir).await;
+    let failing_names: HashSet<&str> = failing.iter().map(|n| n.name.as_str()).collect();
+    for library in libs
+        .iter()
+        .filter(|n| !failing_names.contains(n.name.as_str()))
+    {
+        if let Some((file, path)) = installer.register_library(library, &clean_classpath)? {
+            ForgeInstaller::add_to_classpath(&classpath, &path, &file);
+        }
+    }
+    pt!(
+        "{} already downloaded, {} to download",
+        libs.len() - failing.len(),
+        failing.len()
+    );
+
+    let num_libraries = failing.len();
     let library_i = Mutex::new(0);
-    let jobs: Vec<_> = libs
+    let jobs: Vec<_> = failing
         .into_iter()
         .map(|library| {
             installer.download_library(
@@ -18,7 +18,11 @@ pub async fn uninstall(instance: Instance) -> Result<(), String> {
 }
 
 async fn uninstall_client(instance_dir: &Path, instance: Instance) -> Result<(), String> {
-    let forge_dir = instance_dir.join("forge");
+    let mut config = InstanceConfigJson::read_from_dir(instance_dir)
+        .await
+        .strerr()?;
+
+    let forge_dir = config.get_forge_dir(instance_dir);
     if forge_dir.is_dir() {
         if let Err(err) = tokio::fs::remove_dir_all(&forge_dir)
             .await
@@ -29,9 +33,6 @@ async fn uninstall_client(instance_dir: &Path, instance: Instance) -> Result<(),
         }
     }
 
-    let mut config = InstanceConfigJson::read_from_dir(instance_dir)
-        .await
-        .strerr()?;
     config.mod_type = if let Some(jar) = config
         .mod_type_info
         .as_ref()
@@ -64,6 +65,9 @@ async fn uninstall_client(instance_dir: &Path, instance: Instance) -> Result<(),
 }
 
 async fn uninstall_server(instance_dir: &Path) -> Result<(), ForgeInstallError> {
+    let forge_dir = InstanceConfigJson::read_from_dir(instance_dir)
+        .await?
+        .get_forge_dir(instance_dir);
     change_instance_type(instance_dir, Loader::Vanilla, None).await?;
 
     if let Some(forge_shim_file) = find_forge_shim_file(instance_dir).await {
@@ -78,7 +82,6 @@ async fn uninstall_server(instance_dir: &Path) -> Result<(), ForgeInstallError>
             .await
             .path(libraries_dir)?;
     }
-    let forge_dir = instance_dir.join("forge");
     if forge_dir.is_dir() {
         tokio::fs::remove_dir_all(&forge_dir)
             .await
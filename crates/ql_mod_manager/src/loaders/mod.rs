@@ -1,5 +1,5 @@
 use std::{
-    path::Path,
+    path::{Path, PathBuf},
     sync::{
         Arc,
         mpsc::{Receiver, Sender},
@@ -7,10 +7,13 @@ use std::{
 };
 
 use crate::loaders::paper::PaperVer;
-use forge::ForgeInstallProgress;
+use forge::{ForgeInstallProgress, Is404NotFound};
+use owo_colors::OwoColorize;
 use ql_core::{
-    GenericProgress, Instance, IntoStringError, JsonFileError, Loader, Progress,
-    json::{InstanceConfigJson, instance_config::ModTypeInfo},
+    GenericProgress, Instance, IntoIoError, IntoJsonError, IntoStringError, JsonFileError, Loader,
+    OptifineUniqueVersion, Progress, RequestError, download, err, file_utils,
+    json::{InstanceConfigJson, VersionDetails, instance_config::ModTypeInfo},
+    pt,
 };
 
 pub mod fabric;
@@ -24,6 +27,32 @@ pub(crate) const FORGE_INSTALLER_CLIENT: &[u8] =
 pub(crate) const FORGE_INSTALLER_SERVER: &[u8] =
     include_bytes!("../../../../assets/installers/forge/ForgeInstallerServer.class");
 
+/// Downloads a file from the first of `urls` that works,
+/// trying them in order. Only a 404 moves on to the next one.
+///
+/// # Errors
+/// - Any error other than 404 (returned right away)
+/// - If every url gives 404, the error of the last one
+async fn try_downloading_from_urls(urls: &[&str]) -> Result<Vec<u8>, RequestError> {
+    let mut last_err = RequestError::Message("no urls to download from".to_owned());
+    for url in urls {
+        match download(url).bytes().await {
+            Ok(file) => {
+                pt!("{}: {}", "Url".underline(), url.bright_black());
+                return Ok(file);
+            }
+            Err(err) if err.is_not_found() => last_err = err,
+            Err(err) => return Err(err),
+        }
+    }
+    Err(last_err)
+}
+
+/// Sets the loader of an instance in its `config.json`.
+///
+/// `config.json` is backed up to `config.json.bak` first,
+/// and restored from it if writing fails, so the instance
+/// never ends up with a missing or half-written config.
 async fn change_instance_type(
     instance_dir: &Path,
     loader: Loader,
@@ -32,7 +61,21 @@ async fn change_instance_type(
     let mut config = InstanceConfigJson::read_from_dir(instance_dir).await?;
     config.mod_type = loader;
     config.mod_type_info = extras;
-    config.save_to_dir(instance_dir).await?;
+    let text = serde_json::to_string_pretty(&config).json_to()?;
+
+    let path = instance_dir.join("config.json");
+    let backup_path = instance_dir.join("config.json.bak");
+    tokio::fs::copy(&path, &backup_path)
+        .await
+        .path(&backup_path)?;
+
+    if let Err(err) = file_utils::atomic_write(&path, text).await {
+        if let Err(restore_err) = tokio::fs::rename(&backup_path, &path).await {
+            err!("Couldn't restore config.json from backup: {restore_err}");
+        }
+        return Err(err.into());
+    }
+    _ = tokio::fs::remove_file(&backup_path).await;
     Ok(())
 }
 
@@ -43,11 +86,18 @@ pub enum LoaderInstallResult {
     Unsupported,
 }
 
+/// Installs `loader` on `instance`.
+///
+/// `loader_extra` is loader-specific extra input:
+/// - OptiFine: path to the installer jar. If not provided,
+///   [`LoaderInstallResult::NeedsOptifine`] is returned
+///   so the caller can ask the user for one.
 pub async fn install_specified_loader(
     instance: Instance,
     loader: Loader,
     progress: Option<Arc<Sender<GenericProgress>>>,
     specified_version: Option<String>,
+    loader_extra: Option<PathBuf>,
 ) -> Result<LoaderInstallResult, String> {
     match loader {
         Loader::Vanilla => {}
@@ -116,11 +166,22 @@ pub async fn install_specified_loader(
         }
 
         Loader::OptiFine => {
-            return Ok(if instance.is_server() {
-                LoaderInstallResult::Unsupported
-            } else {
-                LoaderInstallResult::NeedsOptifine
-            });
+            if instance.is_server() {
+                return Ok(LoaderInstallResult::Unsupported);
+            }
+            let Some(installer) = loader_extra else {
+                return Ok(LoaderInstallResult::NeedsOptifine);
+            };
+            let details = VersionDetails::load(&instance).await.strerr()?;
+            optifine::install(
+                instance,
+                installer,
+                None,
+                None,
+                OptifineUniqueVersion::from_version(details.get_id()),
+            )
+            .await
+            .strerr()?;
         }
 
         Loader::Liteloader | Loader::Modloader | Loader::Rift => {
@@ -130,6 +191,39 @@ pub async fn install_specified_loader(
     Ok(LoaderInstallResult::Ok)
 }
 
+/// Roughly estimates how many bytes need to be downloaded
+/// to install `loader` for Minecraft `version` (eg: `1.20.1`).
+///
+/// This is only meant for showing to the user.
+/// Returns `None` if unknown or not applicable (eg: OptiFine,
+/// whose installer is provided by the user).
+#[must_use]
+pub fn get_loader_install_size_estimate(loader: Loader, version: &str) -> Option<u64> {
+    const MB: u64 = 1024 * 1024;
+    let size = match loader {
+        Loader::Fabric | Loader::Quilt => 10 * MB,
+        // 1.13 and above run install processors,
+        // which download mappings and patch the game jar
+        Loader::Forge => match version
+            .strip_prefix("1.")
+            .and_then(|n| n.split(['.', '-']).next())
+            .and_then(|n| n.parse::<u32>().ok())
+        {
+            Some(minor) if minor >= 13 => 200 * MB,
+            Some(_) => 50 * MB,
+            None => return None,
+        },
+        Loader::NeoForge => 80 * MB,
+        Loader::Paper => 50 * MB,
+        Loader::Vanilla
+        | Loader::OptiFine
+        | Loader::Liteloader
+        | Loader::Modloader
+        | Loader::Rift => return None,
+    };
+    Some(size)
+}
+
 fn pipe_progress(rec: Receiver<ForgeInstallProgress>, snd: &Sender<GenericProgress>) {
     for item in rec {
         _ = snd.send(item.into_generic());
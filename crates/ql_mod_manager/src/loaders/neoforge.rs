@@ -14,7 +14,7 @@ use tokio::{fs, process::Command};
 
 use crate::loaders::change_instance_type;
 
-use super::forge::{ForgeInstallError, ForgeInstallProgress};
+use super::forge::{ForgeInstallError, ForgeInstallProgress, get_forge_dir_for_instance};
 
 const NEOFORGE_VERSIONS_URL: &str =
     "https://maven.neoforged.net/api/maven/versions/releases/net/neoforged/neoforge";
@@ -40,13 +40,17 @@ pub async fn install(
     let installer_bytes = get_installer(f_progress, &neoforge_version).await?;
 
     let instance_dir = instance.get_instance_path();
-    let neoforge_dir = instance_dir.join("forge");
-    fs::create_dir_all(&neoforge_dir)
-        .await
-        .path(&neoforge_dir)?;
-    if !instance.is_server() {
-        create_required_jsons(&neoforge_dir).await?;
-    }
+    let neoforge_dir = if instance.is_server() {
+        // Temporary, deleted after installing.
+        // The installer's classpath expects it to be at `forge/`
+        let dir = instance_dir.join("forge");
+        fs::create_dir_all(&dir).await.path(&dir)?;
+        dir
+    } else {
+        let dir = get_forge_dir_for_instance(&instance).await?;
+        create_required_jsons(&dir).await?;
+        dir
+    };
 
     let installer_path = neoforge_dir.join(INSTALLER_NAME);
     fs::write(&installer_path, &installer_bytes)
@@ -14,6 +14,9 @@ use thiserror::Error;
 
 use crate::loaders::change_instance_type;
 
+/// The file name Paper is downloaded to, inside the server directory.
+const PAPER_JAR: &str = "paper_server.jar";
+
 /// Moves a directory from `old_path` to `new_path`.
 /// If `new_path` exists, it will be deleted before the move.
 async fn move_dir(old_path: &Path, new_path: &Path) -> Result<(), IoError> {
@@ -70,7 +73,7 @@ pub async fn install(instance_name: String, version: PaperVer) -> Result<(), Pap
     let version = version.get(json.get_id()).await?;
 
     pt!("Downloading jar");
-    let jar_path = server_dir.join("paper_server.jar");
+    let jar_path = server_dir.join(PAPER_JAR);
     download(&version.downloads.server.url)
         .user_agent_ql()
         .path(&jar_path)
@@ -132,30 +135,45 @@ pub struct PaperDownloadsInner {
     url: String,
 }
 
+/// Uninstalls Paper from a server, turning it back into Vanilla.
+///
+/// Paper runs from its own jar, leaving the Vanilla `server.jar`
+/// untouched. But if `server.jar` has gone missing,
+/// it's redownloaded.
 pub async fn uninstall(instance_name: String) -> Result<(), PaperInstallerError> {
     let server_dir = LAUNCHER_DIR.join("servers").join(instance_name);
 
-    let jar_path = server_dir.join("paper_server.jar");
-    tokio::fs::remove_file(&jar_path).await.path(jar_path)?;
+    let jar_path = server_dir.join(PAPER_JAR);
+    if exists(&jar_path).await {
+        tokio::fs::remove_file(&jar_path).await.path(jar_path)?;
+    }
 
     // Paper stores Nether and End dimension worlds
     // in a separate directory, so we migrate it back.
+    // (these won't exist if the server was never run)
+    for (paper_dir, dim) in [("world_nether", "DIM-1"), ("world_the_end", "DIM1")] {
+        let paper_dir = server_dir.join(paper_dir);
+        let dim_dir = paper_dir.join(dim);
+        if exists(&dim_dir).await {
+            move_dir(&dim_dir, &server_dir.join("world").join(dim)).await?;
+        }
+        if exists(&paper_dir).await {
+            tokio::fs::remove_dir_all(&paper_dir)
+                .await
+                .path(paper_dir)?;
+        }
+    }
 
-    move_dir(
-        &server_dir.join("world_nether/DIM-1"),
-        &server_dir.join("world/DIM-1"),
-    )
-    .await?;
-    move_dir(
-        &server_dir.join("world_the_end/DIM1"),
-        &server_dir.join("world/DIM1"),
-    )
-    .await?;
-
-    let path = server_dir.join("world_nether");
-    tokio::fs::remove_dir_all(&path).await.path(path)?;
-    let path = server_dir.join("world_the_end");
-    tokio::fs::remove_dir_all(&path).await.path(path)?;
+    let vanilla_jar = server_dir.join("server.jar");
+    if !exists(&vanilla_jar).await {
+        pt!("server.jar is missing, redownloading");
+        let json = VersionDetails::load_from_path(&server_dir).await?;
+        let server = json
+            .downloads
+            .server
+            .ok_or(PaperInstallerError::NoServerDownload)?;
+        download(&server.url).path(&vanilla_jar).await?;
+    }
 
     change_instance_type(&server_dir, Loader::Vanilla, None).await?;
 
@@ -174,6 +192,10 @@ pub enum PaperInstallerError {
     Json(#[from] JsonError),
     #[error("{PAPER_INSTALL_ERR_PREFIX}no matching paper version found for {0}")]
     NoMatchingVersionFound(String),
+    #[error(
+        "{PAPER_INSTALL_ERR_PREFIX}couldn't redownload server.jar:\n(details.json).downloads.server is null"
+    )]
+    NoServerDownload,
 }
 
 impl_3_errs_jri!(PaperInstallerError, Json, Request, Io);
@@ -25,6 +25,22 @@ pub struct PresetOutput {
     pub to_install: Vec<ModId>,
 }
 
+/// What loading a preset (with [`Preset::load`])
+/// would change in an instance.
+#[must_use]
+#[derive(Debug, Clone, Default)]
+pub struct PresetDiff {
+    /// Mods in the preset that aren't installed yet.
+    pub to_install: Vec<ModId>,
+    /// Manually installed mods that aren't in the preset.
+    ///
+    /// Note: [`Preset::load`] doesn't uninstall these,
+    /// they just won't be part of the preset.
+    pub to_remove: Vec<ModId>,
+    /// Mods in the preset that are already installed.
+    pub already_present: Vec<ModId>,
+}
+
 /// A "Mod Preset"
 ///
 /// # What are mod presets?
@@ -282,6 +298,52 @@ impl Preset {
             to_install,
         })
     }
+
+    /// Compares the mods in a preset (`.qmp` file)
+    /// with the ones installed in `instance`,
+    /// without changing anything.
+    ///
+    /// Useful for showing a confirmation before
+    /// actually loading the preset with [`Preset::load`].
+    ///
+    /// # Errors
+    /// - `preset_bytes` isn't a valid preset
+    ///   ([`ModError::NotValidPack`] if it lacks an `index.json`)
+    /// - The instance's mod index couldn't be loaded
+    pub async fn diff(instance: &Instance, preset_bytes: &[u8]) -> Result<PresetDiff, ModError> {
+        let index: Self = {
+            let mut zip = zip::ZipArchive::new(Cursor::new(preset_bytes)).map_err(ModError::Zip)?;
+            let Ok(mut index) = zip.by_name("index.json") else {
+                return Err(ModError::NotValidPack);
+            };
+            let buf = std::io::read_to_string(&mut index)
+                .map_err(|n| ModError::ZipIoError(n, "index.json".to_owned()))?;
+            serde_json::from_str(&buf).json(buf)?
+        };
+        let mod_index = ModIndex::load(instance).await?;
+
+        let mut diff = PresetDiff::default();
+        for (id, config) in &index.entries_downloaded {
+            if !config.manually_installed {
+                continue;
+            }
+            if mod_index.mods.contains_key(id) {
+                diff.already_present.push(id.clone());
+            } else {
+                diff.to_install.push(id.clone());
+            }
+        }
+        diff.to_remove = mod_index
+            .mods
+            .iter()
+            .filter(|(id, config)| {
+                config.manually_installed && !index.entries_downloaded.contains_key(*id)
+            })
+            .map(|(id, _)| id.clone())
+            .collect();
+
+        Ok(diff)
+    }
 }
 
 async fn get_instance_type(instance: &Instance) -> Result<Loader, ModError> {
@@ -47,7 +47,11 @@ pub async fn add_files(
             &GenericProgress {
                 done: i,
                 total: len,
-                message: Some(format!("Installing {project_type}: ({}/{len})", i + 1)),
+                message: Some(format!(
+                    "Installing {project_type}: {} ({}/{len})",
+                    filename.to_string_lossy(),
+                    i + 1
+                )),
                 has_finished: false,
             },
         );
@@ -352,7 +352,7 @@ impl Backend for CurseforgeBackend {
         id: &str,
         version: &str,
         loader: Loader,
-    ) -> Result<(DateTime<chrono::FixedOffset>, String), ModError> {
+    ) -> Result<(DateTime<chrono::FixedOffset>, String, Vec<String>), ModError> {
         let response = ModQuery::load(id).await?;
         let loader = loader.not_vanilla().map(|n| n.to_curseforge_num());
 
@@ -370,7 +370,11 @@ impl Backend for CurseforgeBackend {
 
         let download_version_time = DateTime::parse_from_rfc3339(&file_query.data.fileDate)?;
 
-        Ok((download_version_time, file_query.data.displayName))
+        Ok((
+            download_version_time,
+            file_query.data.displayName,
+            file_query.data.gameVersions,
+        ))
     }
 
     async fn download(
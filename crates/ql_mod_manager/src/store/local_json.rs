@@ -2,7 +2,7 @@ use std::{
     collections::{HashMap, HashSet},
     io::ErrorKind,
     path::PathBuf,
-    sync::Arc,
+    sync::{Arc, LazyLock},
 };
 
 use ql_core::{
@@ -16,6 +16,30 @@ use crate::store::{DirStructure, ModId, QueryType};
 
 use super::StoreBackendType;
 
+/// Known Modrinth projects that don't work together
+/// (usually because they replace the same part of the game).
+///
+/// Each conflict only needs to be listed once,
+/// [`ModIndex::find_conflicts`] checks both directions.
+static CONFLICTS: LazyLock<HashMap<&'static str, Vec<&'static str>>> = LazyLock::new(|| {
+    HashMap::from([
+        // Sodium -> Embeddium
+        ("AANobbMI", vec!["sk9rgfiA"]),
+        // Starlight -> Phosphor, ScalableLux
+        ("H8CaAYZC", vec!["hEOCdOgW", "Ps1zyz6x"]),
+        // LazyDFU -> LazyDFU Reloaded
+        ("hvFnDODi", vec!["OmQzuQFa"]),
+    ])
+});
+
+fn is_conflicting(a: &ModId, b: &ModId) -> bool {
+    let (ModId::Modrinth(a), ModId::Modrinth(b)) = (a, b) else {
+        return false;
+    };
+    let check = |a: &str, b: &str| CONFLICTS.get(a).is_some_and(|n| n.contains(&b));
+    check(a, b) || check(b, a)
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct ModConfig {
     pub name: Arc<str>,
@@ -79,6 +103,24 @@ impl ModIndex {
         }
     }
 
+    /// Checks the given mods against the installed ones
+    /// (and each other) for known conflicts.
+    ///
+    /// Returns pairs of `(new mod, conflicting mod)`.
+    /// Currently only Modrinth mods are checked.
+    #[must_use]
+    pub fn find_conflicts(&self, mods: &[ModId]) -> Vec<(ModId, ModId)> {
+        let mut conflicts = Vec::new();
+        for (i, id) in mods.iter().enumerate() {
+            for other in self.mods.keys().chain(mods[i + 1..].iter()) {
+                if is_conflicting(id, other) {
+                    conflicts.push((id.clone(), other.clone()));
+                }
+            }
+        }
+        conflicts
+    }
+
     async fn fix(&mut self, instance: Instance) -> Result<(), JsonFileError> {
         // I know this is inefficient, but modern OSes have automatic file caching
         // that basically guarantees this is fast.
@@ -4,7 +4,7 @@ use std::{
 };
 
 use chrono::DateTime;
-use ql_core::{GenericProgress, Instance, Loader, do_jobs, pt};
+use ql_core::{GenericProgress, Instance, Loader, do_jobs, err, pt};
 
 mod add_file;
 mod curseforge;
@@ -36,6 +36,18 @@ pub use types::{
 };
 pub use update::{ChangelogFile, apply_updates, check_for_updates};
 
+/// Warms up the HTTP cache with store metadata (categories, etc.)
+/// in the background, so the mod store opens faster.
+pub async fn preload_metadata() {
+    for result in
+        ql_core::request::url_cache_preload(vec![modrinth::CATEGORIES_URL.to_owned()]).await
+    {
+        if let Err(err) = result {
+            err!(no_log, "Couldn't preload mod store metadata: {err}");
+        }
+    }
+}
+
 #[allow(async_fn_in_trait)]
 pub trait Backend {
     /// # Takes in
@@ -61,12 +73,13 @@ pub trait Backend {
     ///
     /// Useful for update checking.
     ///
-    /// Returns the release date and version name (eg: `v2.0.1`).
+    /// Returns the release date, version name (eg: `v2.0.1`)
+    /// and the Minecraft versions that this file supports.
     async fn get_latest_version_date(
         id: &str,
         version: &str,
         loader: Loader,
-    ) -> Result<(DateTime<chrono::FixedOffset>, String), ModError>;
+    ) -> Result<(DateTime<chrono::FixedOffset>, String, Vec<String>), ModError>;
 
     /// Downloads a single mod to the `instance`.
     ///
@@ -212,7 +225,9 @@ pub async fn download_mods_bulk(
 
 /// Gets the latest compatible mod version, based on provided Minecraft version and mod loader.
 ///
-/// Returns the release date and version name (eg: `v2.0.1`).
+/// Returns the release date, version name (eg: `v2.0.1`)
+/// and the Minecraft versions that this file supports
+/// (eg: `["1.20.1", "1.20.4", "1.21"]`).
 ///
 /// Useful for checking for updates, or checking compatibility.
 ///
@@ -224,7 +239,7 @@ pub async fn get_latest_version_date(
     loader: Loader,
     mod_id: &ModId,
     version: &str,
-) -> Result<(DateTime<chrono::FixedOffset>, String), ModError> {
+) -> Result<(DateTime<chrono::FixedOffset>, String, Vec<String>), ModError> {
     Ok(match mod_id {
         ModId::Modrinth(n) => ModrinthBackend::get_latest_version_date(n, version, loader).await?,
         ModId::Curseforge(n) => {
@@ -19,6 +19,8 @@ use crate::{
 
 use super::{Backend, CurseforgeNotAllowed, ModError, Query, SearchResult};
 
+pub(crate) const CATEGORIES_URL: &str = "https://api.modrinth.com/v2/tag/category";
+
 mod download;
 mod info;
 mod search;
@@ -77,7 +79,7 @@ impl Backend for ModrinthBackend {
         id: &str,
         version: &str,
         loader: Loader,
-    ) -> Result<(DateTime<chrono::FixedOffset>, String), ModError> {
+    ) -> Result<(DateTime<chrono::FixedOffset>, String, Vec<String>), ModError> {
         let download_info = ModVersion::download(id).await?;
         let version = version.to_owned();
 
@@ -108,7 +110,11 @@ impl Backend for ModrinthBackend {
 
         let download_version_time = DateTime::parse_from_rfc3339(&download_version.date_published)?;
 
-        Ok((download_version_time, download_version.version_number))
+        Ok((
+            download_version_time,
+            download_version.version_number,
+            download_version.game_versions,
+        ))
     }
 
     async fn download(
@@ -196,11 +202,7 @@ impl Backend for ModrinthBackend {
         static CACHE: tokio::sync::OnceCell<Vec<MCategory>> = tokio::sync::OnceCell::const_new();
 
         let mcategories = CACHE
-            .get_or_try_init(|| async {
-                download("https://api.modrinth.com/v2/tag/category")
-                    .json()
-                    .await
-            })
+            .get_or_try_init(|| async { download(CATEGORIES_URL).json().await })
             .await?;
         let kind_str = kind.to_modrinth_str();
 
@@ -4,7 +4,7 @@ use std::sync::mpsc::Sender;
 use chrono::DateTime;
 use chrono::Local;
 use ql_core::InstanceConfigJson;
-use ql_core::{GenericProgress, Instance, do_jobs, err, info, json::VersionDetails};
+use ql_core::{GenericProgress, Instance, do_jobs, err, info, json::VersionDetails, pt};
 
 use crate::store::{get_latest_version_date, toggle_mods};
 
@@ -125,14 +125,27 @@ pub async fn check_for_updates(instance: Instance) -> Result<Vec<(ModId, String)
             .mods
             .into_iter()
             .map(|(mod_id, installed_mod)| async move {
-                let (download_version_time, download_version) =
+                let (download_version_time, download_version, game_versions) =
                     get_latest_version_date(loader, &mod_id, version).await?;
 
                 let installed_version_time =
                     DateTime::parse_from_rfc3339(&installed_mod.version_release_time)?;
-
-                Ok((download_version_time > installed_version_time)
-                    .then_some((mod_id, download_version)))
+                if download_version_time <= installed_version_time {
+                    return Ok(None);
+                }
+
+                // Some backends fall back to the "closest" file
+                // if nothing matches, which could be for a different
+                // Minecraft version. Don't "update" to that.
+                if !game_versions.iter().any(|n| n == version) {
+                    pt!(
+                        "Skipping update for {}: not compatible with {version}",
+                        installed_mod.name
+                    );
+                    return Ok(None);
+                }
+
+                Ok(Some((mod_id, download_version)))
             }),
     )
     .await;
@@ -146,6 +146,7 @@ async fn import_quantumlauncher(
         config_json.mod_type,
         sender.clone(),
         None,
+        None,
     )
     .await
     .map_err(InstancePackageError::Loader)?;
@@ -142,6 +142,18 @@ fn progress_manifest(sender: Option<&Sender<DownloadProgress>>) {
     }
 }
 
+/// Accepts the Minecraft EULA for a server
+/// by writing `eula=true` to its `eula.txt`.
+///
+/// Servers created by the launcher already have this,
+/// but imported/copied ones might not.
+///
+/// # Errors
+/// If `eula.txt` couldn't be written to.
+pub async fn accept_eula(name: &str) -> Result<(), ServerError> {
+    write_eula(&LAUNCHER_DIR.join("servers").join(name)).await
+}
+
 async fn write_eula(server_dir: &std::path::Path) -> Result<(), ServerError> {
     let eula_path = server_dir.join("eula.txt");
     tokio::fs::write(&eula_path, "eula=true\n")
@@ -15,7 +15,7 @@ mod create;
 mod run;
 mod server_properties;
 // mod ssh;
-pub use create::{create_server, delete_server};
+pub use create::{accept_eula, create_server, delete_server};
 pub use run::run;
 pub use server_properties::ServerProperties;
 // pub use ssh::run_tunnel;
@@ -76,6 +76,7 @@ pub async fn run(
         child: Arc::new(Mutex::new(child)),
         instance: Instance::server(&name),
         is_classic_server: launcher.is_classic_server(),
+        killed_by_user: Arc::default(),
     })
 }
 
@@ -107,11 +108,7 @@ impl ServerLauncher {
         &self,
         java_install_progress: Option<&Sender<GenericProgress>>,
     ) -> Result<PathBuf, ServerError> {
-        let version = if let Some(version) = self.version_json.javaVersion.clone() {
-            version.into()
-        } else {
-            JavaVersion::Java8
-        };
+        let version = JavaVersion::required_for(&self.version_json);
 
         if let Some(java_path) = self.config.get_java_override() {
             return Ok(java_path);
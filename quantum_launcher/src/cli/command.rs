@@ -1,14 +1,20 @@
 use owo_colors::{OwoColorize, Style};
 use ql_core::{
-    Instance, InstanceKind, IntoStringError, LAUNCHER_DIR, ListEntry, Loader,
-    OptifineUniqueVersion, clean, eeprintln, err, info,
+    DownloadProgress, Instance, InstanceKind, IntoStringError, LAUNCHER_DIR, LaunchedProcess,
+    ListEntry, Loader, OptifineUniqueVersion, clean, eeprintln, err, info,
     json::{InstanceConfigJson, VersionDetails},
 };
-use ql_mod_manager::loaders::LoaderInstallResult;
-use std::{path::PathBuf, process::exit, sync::Arc};
+use ql_mod_manager::{loaders::LoaderInstallResult, store::ModIndex};
+use std::{
+    path::PathBuf,
+    process::exit,
+    sync::{Arc, mpsc::Receiver},
+};
+use tokio::io::AsyncWriteExt;
+use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    cli::{CleanType, QLoader, account::refresh_account, helpers::render_row},
+    cli::{CleanType, ListFormat, QLoader, account::refresh_account, helpers::render_row},
     message_update::format_memory_bytes,
     state::get_entries,
 };
@@ -80,9 +86,8 @@ pub async fn clean_cache(kinds: Vec<CleanType>) -> Result<(), Box<dyn std::error
 pub fn list_instances(
     properties: Option<&[String]>,
     kind: InstanceKind,
+    format: ListFormat,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    use std::fmt::Write;
-
     let mut cmds: Vec<PrintCmd> = properties
         .unwrap_or_default()
         .iter()
@@ -94,82 +99,158 @@ pub fn list_instances(
         })
         .collect();
     if cmds.is_empty() {
-        cmds.push(PrintCmd::Name);
+        if let ListFormat::Plain = format {
+            cmds.push(PrintCmd::Name);
+        } else {
+            cmds.extend([PrintCmd::Name, PrintCmd::Version, PrintCmd::Loader]);
+        }
     }
 
     let runtime = tokio::runtime::Runtime::new()?;
+    let (instances, _) = runtime.block_on(get_entries(kind))?;
+
+    let rows: Vec<InstanceRow> = instances
+        .into_iter()
+        .map(|name| {
+            let instance_dir = kind.get_root_directory().join(&name);
+            let version = cmds
+                .contains(&PrintCmd::Version)
+                .then(|| runtime.block_on(VersionDetails::load_from_path(&instance_dir)))
+                .and_then(|res| res.inspect_err(|err| err!("{err}")).ok())
+                .map(|json| json.id);
+            let loader = cmds
+                .contains(&PrintCmd::Loader)
+                .then(|| runtime.block_on(InstanceConfigJson::read_from_dir(&instance_dir)))
+                .and_then(|res| res.inspect_err(|err| err!("{err}")).ok())
+                .map(|json| json.mod_type);
+            InstanceRow {
+                name,
+                version,
+                loader,
+            }
+        })
+        .collect();
 
-    let (instances, _) = tokio::runtime::Runtime::new()?.block_on(get_entries(kind))?;
+    match format {
+        ListFormat::Plain => print_instances_plain(&rows, &cmds),
+        ListFormat::Table => print_instances_table(&rows, &cmds),
+        ListFormat::Json => {
+            println!(
+                "{}",
+                serde_json::to_string_pretty(&instances_to_json(&rows, &cmds))?
+            );
+        }
+    }
+    Ok(())
+}
 
-    let mut cmds_name = String::new();
-    let mut cmds_version = String::new();
-    let mut cmds_loader = String::new();
+struct InstanceRow {
+    name: String,
+    version: Option<String>,
+    loader: Option<Loader>,
+}
 
-    for instance in instances {
-        let instance_dir = kind.get_root_directory().join(&instance);
-        for cmd in &cmds {
-            match cmd {
-                PrintCmd::Name => {
-                    cmds_name.push_str(&instance);
-                    cmds_name.push('\n');
-                }
-                PrintCmd::Version => {
-                    match runtime.block_on(VersionDetails::load_from_path(&instance_dir)) {
-                        Ok(json) => {
-                            cmds_version.push_str(&json.id);
-                        }
-                        Err(err) => {
-                            err!("{err}");
-                        }
-                    }
-                    cmds_version.push('\n');
-                }
-                PrintCmd::Loader => {
-                    let config_json =
-                        match runtime.block_on(InstanceConfigJson::read_from_dir(&instance_dir)) {
-                            Ok(json) => json,
-                            Err(err) => {
-                                err!("{err}");
-                                cmds_loader.push('\n');
-                                continue;
-                            }
-                        };
-                    let m = config_json.mod_type;
-
-                    match m {
-                        Loader::Vanilla => writeln!(cmds_loader, "{}", m.bright_black()),
-                        Loader::Fabric => writeln!(cmds_loader, "{}", m.bright_green()),
-                        Loader::Quilt => writeln!(cmds_loader, "{}", m.bright_purple()),
-                        Loader::Forge => writeln!(cmds_loader, "{}", m.bright_yellow()),
-                        Loader::NeoForge => writeln!(cmds_loader, "{}", m.yellow()),
-                        Loader::OptiFine => writeln!(cmds_loader, "{}", m.red().bold()),
-                        Loader::Paper => writeln!(cmds_loader, "{}", m.blue()),
-                        Loader::Liteloader => writeln!(cmds_loader, "{}", m.bright_blue()),
-                        Loader::Modloader => writeln!(cmds_loader, "{m}"),
-                        Loader::Rift => writeln!(cmds_loader, "{}", m.bold().underline()),
-                    }?;
-                }
-            }
+impl InstanceRow {
+    fn get(&self, cmd: PrintCmd) -> String {
+        match cmd {
+            PrintCmd::Name => self.name.clone(),
+            PrintCmd::Version => self.version.clone().unwrap_or_default(),
+            PrintCmd::Loader => self.loader.map(|n| n.to_string()).unwrap_or_default(),
         }
     }
+}
+
+/// One colored column per property
+fn print_instances_plain(rows: &[InstanceRow], cmds: &[PrintCmd]) {
+    let columns: Vec<String> = cmds
+        .iter()
+        .map(|cmd| {
+            rows.iter()
+                .map(|row| match (cmd, row.loader) {
+                    (PrintCmd::Loader, Some(loader)) => colored_loader(loader),
+                    _ => row.get(*cmd),
+                })
+                .fold(String::new(), |mut out, line| {
+                    out.push_str(&line);
+                    out.push('\n');
+                    out
+                })
+        })
+        .collect();
 
     let Some((terminal_size::Width(width), _)) = terminal_size::terminal_size() else {
-        println!("{cmds_name}\n\n{cmds_loader}\n\n{cmds_version}");
-        return Ok(());
+        println!("{}", columns.join("\n\n"));
+        return;
     };
 
-    let cmds: Vec<(String, Option<Style>)> = cmds
+    let columns: Vec<(String, Option<Style>)> = columns.into_iter().map(|n| (n, None)).collect();
+    print!("{}", render_row(width, &columns, true).unwrap());
+}
+
+fn colored_loader(m: Loader) -> String {
+    match m {
+        Loader::Vanilla => m.bright_black().to_string(),
+        Loader::Fabric => m.bright_green().to_string(),
+        Loader::Quilt => m.bright_purple().to_string(),
+        Loader::Forge => m.bright_yellow().to_string(),
+        Loader::NeoForge => m.yellow().to_string(),
+        Loader::OptiFine => m.red().bold().to_string(),
+        Loader::Paper => m.blue().to_string(),
+        Loader::Liteloader => m.bright_blue().to_string(),
+        Loader::Modloader => m.to_string(),
+        Loader::Rift => m.bold().underline().to_string(),
+    }
+}
+
+/// Aligned columns with a header, no colors
+fn print_instances_table(rows: &[InstanceRow], cmds: &[PrintCmd]) {
+    let header: Vec<String> = cmds
         .iter()
-        .map(|n| match n {
-            PrintCmd::Name => (cmds_name.clone(), None),
-            PrintCmd::Version => (cmds_version.clone(), None),
-            PrintCmd::Loader => (cmds_loader.clone(), None),
+        .map(|cmd| {
+            match cmd {
+                PrintCmd::Name => "NAME",
+                PrintCmd::Version => "VERSION",
+                PrintCmd::Loader => "LOADER",
+            }
+            .to_owned()
         })
         .collect();
+    let lines: Vec<Vec<String>> = std::iter::once(header)
+        .chain(
+            rows.iter()
+                .map(|row| cmds.iter().map(|cmd| row.get(*cmd)).collect()),
+        )
+        .collect();
 
-    print!("{}", render_row(width, &cmds, true).unwrap());
+    let widths: Vec<usize> = (0..cmds.len())
+        .map(|i| lines.iter().map(|line| line[i].width()).max().unwrap_or(0))
+        .collect();
 
-    Ok(())
+    for line in lines {
+        let mut out = String::new();
+        for (cell, width) in line.iter().zip(&widths) {
+            out.push_str(cell);
+            out.push_str(&" ".repeat(width - cell.width() + 2));
+        }
+        println!("{}", out.trim_end());
+    }
+}
+
+fn instances_to_json(rows: &[InstanceRow], cmds: &[PrintCmd]) -> serde_json::Value {
+    rows.iter()
+        .map(|row| {
+            let mut obj = serde_json::Map::new();
+            for cmd in cmds {
+                let (key, value) = match cmd {
+                    PrintCmd::Name => ("name", serde_json::json!(row.name)),
+                    PrintCmd::Version => ("version", serde_json::json!(row.version)),
+                    PrintCmd::Loader => ("loader", serde_json::json!(row.loader)),
+                };
+                obj.insert(key.to_owned(), value);
+            }
+            serde_json::Value::Object(obj)
+        })
+        .collect()
 }
 
 pub async fn create_instance(
@@ -179,19 +260,44 @@ pub async fn create_instance(
     kind: InstanceKind,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let entry = ListEntry::new(version);
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let printer = std::thread::spawn(move || print_download_progress(&receiver));
 
     match kind {
         InstanceKind::Client => {
-            ql_instances::create_instance(instance_name, entry, None, !skip_assets).await?;
+            ql_instances::create_instance(instance_name, entry, Some(sender), !skip_assets).await?;
         }
         InstanceKind::Server => {
-            ql_servers::create_server(instance_name, entry, None).await?;
+            let result = ql_servers::create_server(instance_name, entry, Some(&sender)).await;
+            drop(sender);
+            result?;
         }
     }
+    _ = printer.join();
 
     Ok(())
 }
 
+/// Prints download progress on a single updating line per stage,
+/// until the sender is dropped.
+fn print_download_progress(receiver: &Receiver<DownloadProgress>) {
+    use std::io::Write;
+
+    let mut last_stage = None;
+    for progress in receiver {
+        let stage = std::mem::discriminant(&progress);
+        if last_stage.is_some_and(|n| n != stage) {
+            eprintln!();
+        }
+        last_stage = Some(stage);
+        eprint!("\r{progress}");
+        _ = std::io::stderr().flush();
+    }
+    if last_stage.is_some() {
+        eprintln!();
+    }
+}
+
 pub fn delete_instance(
     instance_name: &str,
     force: bool,
@@ -270,8 +376,11 @@ pub async fn launch_instance(
             )
             .await?
         }
-        // TODO: stdin input
-        InstanceKind::Server => ql_servers::run(instance_name, None).await?,
+        InstanceKind::Server => {
+            let child = ql_servers::run(instance_name, None).await?;
+            pipe_server_stdin(&child).await;
+            child
+        }
     };
 
     let mut censors = Vec::new();
@@ -279,7 +388,11 @@ pub async fn launch_instance(
         censors.push(token.clone());
     }
 
-    match child.read_logs(censors, None).await {
+    let hang_timeout = InstanceConfigJson::read(&child.instance)
+        .await
+        .ok()
+        .and_then(|n| n.get_hang_timeout());
+    match child.read_logs(censors, None, hang_timeout).await {
         Some(Ok((s, _, diag))) => {
             info!("Game exited with code {s}");
             if let Some(diag) = diag {
@@ -293,10 +406,53 @@ pub async fn launch_instance(
     Ok(())
 }
 
+/// Forwards the terminal's stdin to the server,
+/// so commands can be typed in directly.
+///
+/// On `Ctrl+C`, sends `stop` to the server so it can
+/// save and shut down gracefully. A second `Ctrl+C`
+/// kills it.
+async fn pipe_server_stdin(child: &LaunchedProcess) {
+    let Some(mut server_stdin) = child.child.lock().await.stdin.take() else {
+        // Logging is disabled for this server, so stdin isn't piped
+        return;
+    };
+    let process = child.clone();
+
+    tokio::spawn(async move {
+        tokio::select! {
+            result = tokio::io::copy(&mut tokio::io::stdin(), &mut server_stdin) => {
+                if let Err(err) = result {
+                    err!("Couldn't forward input to server: {err}");
+                }
+                return;
+            }
+            _ = tokio::signal::ctrl_c() => {}
+        }
+
+        info!("Stopping server (press Ctrl+C again to force quit)");
+        if let Err(err) = async {
+            server_stdin.write_all(b"stop\n").await?;
+            server_stdin.flush().await
+        }
+        .await
+        {
+            err!("Couldn't send stop command to server: {err}");
+        }
+
+        if tokio::signal::ctrl_c().await.is_ok() {
+            err!("Force quitting server");
+            process.mark_killed_by_user();
+            _ = process.child.lock().await.start_kill();
+        }
+    });
+}
+
 pub async fn loader(cmd: QLoader, kind: InstanceKind) -> Result<(), Box<dyn std::error::Error>> {
     match cmd {
         QLoader::Info { instance } => {
-            let json = InstanceConfigJson::read(&Instance::new(&instance, kind)).await?;
+            let instance = Instance::new(&instance, kind);
+            let json = InstanceConfigJson::read(&instance).await?;
             println!("Kind: {}", json.mod_type);
             if let Some(info) = json.mod_type_info {
                 if let Some(version) = info.version {
@@ -309,6 +465,10 @@ pub async fn loader(cmd: QLoader, kind: InstanceKind) -> Result<(), Box<dyn std:
                     println!("OptiFine Installation: {jar}");
                 }
             }
+            match ModIndex::load(&instance).await {
+                Ok(index) => println!("Installed Mods: {}", index.mods.len()),
+                Err(err) => err!("Couldn't read mod index: {err}"),
+            }
         }
         QLoader::Install {
             instance,
@@ -354,6 +514,7 @@ pub async fn loader(cmd: QLoader, kind: InstanceKind) -> Result<(), Box<dyn std:
                 loader,
                 None,
                 version,
+                None,
             )
             .await?
             {
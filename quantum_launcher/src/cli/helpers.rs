@@ -1,26 +1,36 @@
 use owo_colors::{OwoColorize, Style};
 use ql_core::print::strip_ansi_codes;
 use std::{fmt::Write, io::IsTerminal};
+use unicode_width::UnicodeWidthStr;
 
 #[must_use]
 pub fn render_row(
     width: u16,
     items: &[(String, Option<Style>)],
     list_view: bool,
+) -> Option<String> {
+    render_row_inner(width, items, list_view, std::io::stdout().is_terminal())
+}
+
+fn render_row_inner(
+    width: u16,
+    items: &[(String, Option<Style>)],
+    list_view: bool,
+    is_terminal: bool,
 ) -> Option<String> {
     let mut out = String::new();
     let max_widths: Vec<usize> = items
         .iter()
         .map(|n| {
             n.0.lines()
-                .map(|n| strip_ansi_codes(n).chars().count())
+                .map(display_width)
                 .max()
                 .map(|n| n + 2)
                 .unwrap_or_default()
         })
         .collect();
 
-    if !std::io::stdout().is_terminal() {
+    if !is_terminal {
         render_row_basic(items, &mut out);
         return Some(out);
     }
@@ -47,7 +57,7 @@ pub fn render_row(
                     }
 
                     let cell = pad_line(item.lines().nth(line_i), max_widths[item_i]);
-                    let cell_width = strip_ansi_codes(&cell).chars().count();
+                    let cell_width = display_width(&cell);
 
                     // Check if adding this cell would exceed width
                     if current_width > 0 && current_width + 1 + cell_width > width as usize {
@@ -117,9 +127,15 @@ fn write_line(out: &mut String, style: Option<Style>, line: &str) {
     }
 }
 
+/// Width of `line` in terminal columns,
+/// ignoring ANSI codes (wide characters like CJK take up 2).
+fn display_width(line: &str) -> usize {
+    strip_ansi_codes(line).width()
+}
+
 fn pad_line(line: Option<&str>, width: usize) -> String {
     let line = line.unwrap_or_default();
-    let visible_len = strip_ansi_codes(line).chars().count();
+    let visible_len = display_width(line);
     if visible_len < width {
         let pad = width - visible_len;
         format!("{line}{:pad$}", "")
@@ -127,3 +143,64 @@ fn pad_line(line: Option<&str>, width: usize) -> String {
         line.to_owned()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items(list: &[&str]) -> Vec<(String, Option<Style>)> {
+        list.iter().map(|n| ((*n).to_owned(), None)).collect()
+    }
+
+    fn render(width: u16, list: &[&str], list_view: bool) -> Option<String> {
+        render_row_inner(width, &items(list), list_view, true).map(|n| strip_ansi_codes(&n))
+    }
+
+    #[test]
+    fn both_panels_fit() {
+        let out = render(40, &["ab\ncd", "xyz\nw"], false).unwrap();
+        assert_eq!(out, "ab  xyz  \ncd  w    \n");
+    }
+
+    #[test]
+    fn only_left_panel_fits() {
+        // Each panel fits on its own, but not side-by-side,
+        // so they get stacked
+        let out = render(6, &["ab\ncd", "xyz"], false).unwrap();
+        assert_eq!(out, "ab\ncd\nxyz\n");
+    }
+
+    #[test]
+    fn neither_panel_fits() {
+        assert_eq!(render(3, &["abcd", "xyz"], false), None);
+        // List view falls back to tab-separated columns
+        let out = render(3, &["abcd", "xyz"], true).unwrap();
+        assert_eq!(out, "abcd\txyz\n");
+    }
+
+    #[test]
+    fn different_line_counts() {
+        let out = render(40, &["a", "b\nc\nd"], false).unwrap();
+        assert_eq!(out, "a  b  \n   c  \n   d  \n");
+    }
+
+    #[test]
+    fn wide_characters_use_display_columns() {
+        // "日本語" is 3 chars but 6 columns wide
+        assert_eq!(display_width("日本語"), 6);
+        assert_eq!(display_width("héllo"), 5);
+
+        let out = render(40, &["日本\nab", "x"], false).unwrap();
+        assert_eq!(out, "日本  x  \nab       \n");
+
+        // Would fit if measured in chars (5 + 3), but not in columns (8 + 3)
+        let out = render(9, &["日本語", "x"], false).unwrap();
+        assert_eq!(out, "日本語\nx\n");
+    }
+
+    #[test]
+    fn non_terminal_is_tab_separated() {
+        let out = render_row_inner(40, &items(&["ab\ncd", "x"]), false, false).unwrap();
+        assert_eq!(out, "ab\tx\ncd\t\n");
+    }
+}
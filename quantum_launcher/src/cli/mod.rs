@@ -85,7 +85,11 @@ enum QSubCommand {
     },
     #[command(aliases = ["list", "list-instances"], short_flag = 'l')]
     #[command(about = "Lists installed instances")]
-    ListInstalled { properties: Option<Vec<String>> },
+    ListInstalled {
+        properties: Option<Vec<String>>,
+        #[arg(long, value_enum, default_value_t)]
+        format: ListFormat,
+    },
     #[command(about = "Deletes the specified instance")]
     Delete {
         instance_name: String,
@@ -100,6 +104,17 @@ enum QSubCommand {
     ListAvailableVersions,
 }
 
+#[derive(ValueEnum, Clone, Copy, Debug, Default)]
+enum ListFormat {
+    /// One column per property (default)
+    #[default]
+    Plain,
+    /// Aligned columns with a header
+    Table,
+    /// A JSON array of objects
+    Json,
+}
+
 #[derive(ValueEnum, Clone, Debug)]
 enum CleanType {
     Assets,
@@ -172,12 +187,15 @@ fn print_intro() {
     const LOGO: &str = include_str!("../../../assets/ascii/icon.txt");
     const LOGO_WIDTH: u16 = 30;
 
-    let text = get_right_text();
-
-    let Some((terminal_size::Width(width), _)) = terminal_size::terminal_size() else {
+    let Some((terminal_size::Width(width), _)) =
+        terminal_size::terminal_size().filter(|_| !is_plain_terminal())
+    else {
+        print_intro_plain();
         return;
     };
 
+    let text = get_right_text();
+
     let draw_contents = &[
         (LOGO.to_owned(), Some(Style::new().purple().bold())),
         (text.clone(), None),
@@ -199,6 +217,22 @@ fn print_intro() {
     }
 }
 
+/// Whether the terminal should get plain text
+/// instead of colored ASCII art (`TERM=dumb` or `NO_COLOR` set).
+fn is_plain_terminal() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|n| !n.is_empty())
+        || std::env::var_os("TERM").is_some_and(|n| n == "dumb")
+}
+
+fn print_intro_plain() {
+    println!(
+        "Quantum Launcher {LAUNCHER_VERSION_NAME}
+A simple, powerful Minecraft launcher
+For a list of commands type: ./quantum_launcher --help
+"
+    );
+}
+
 fn get_right_text() -> String {
     const TEXT: &str = include_str!("../../../assets/ascii/text.txt");
 
@@ -300,8 +334,8 @@ pub fn start_cli(is_dir_err: bool, launcher_dir: &mut Option<PathBuf>) {
                 force,
             } => quit(command::delete_instance(&instance_name, force, kind)),
             QSubCommand::Clean { kinds } => quit(runtime.block_on(command::clean_cache(kinds))),
-            QSubCommand::ListInstalled { properties } => {
-                quit(command::list_instances(properties.as_deref(), kind));
+            QSubCommand::ListInstalled { properties, format } => {
+                quit(command::list_instances(properties.as_deref(), kind, format));
             }
             QSubCommand::Loader(cmd) => {
                 quit(runtime.block_on(command::loader(cmd, kind)));
@@ -4,7 +4,7 @@ use crate::stylesheet::styles::{LauncherTheme, LauncherThemeColor, LauncherTheme
 use crate::{WINDOW_HEIGHT, WINDOW_WIDTH};
 use ql_core::{
     InstanceKind, IntoIoError, IntoJsonError, JsonFileError, LAUNCHER_DIR, LAUNCHER_VERSION_NAME,
-    ListEntryKind, err, json::GlobalSettings,
+    ListEntryKind, err, file_utils, json::GlobalSettings,
 };
 use ql_instances::auth::{AccountData, AccountType};
 use serde::{Deserialize, Serialize};
@@ -17,9 +17,29 @@ use std::{
 pub mod discord_rpc;
 pub mod sidebar;
 
+/// Default width of the sidebar, as a fraction of the window width.
 pub const SIDEBAR_WIDTH: f32 = 0.33;
+/// Minimum width of the sidebar, in pixels.
+pub const SIDEBAR_LIMIT_LEFT: f32 = 135.0;
+/// Minimum space left to the right of the sidebar, in pixels.
+pub const SIDEBAR_LIMIT_RIGHT: f32 = 140.0;
 const OPACITY: f32 = 0.9;
 
+/// Clamps a sidebar width (`ratio` of `window_width`) so that
+/// the sidebar stays between [`SIDEBAR_LIMIT_LEFT`] and
+/// [`SIDEBAR_LIMIT_RIGHT`].
+///
+/// If the window is too small for both limits,
+/// [`SIDEBAR_WIDTH`] is used instead.
+#[must_use]
+pub fn clamp_sidebar_ratio(ratio: f32, window_width: f32) -> f32 {
+    if window_width <= SIDEBAR_LIMIT_LEFT + SIDEBAR_LIMIT_RIGHT {
+        return SIDEBAR_WIDTH;
+    }
+    (ratio * window_width).clamp(SIDEBAR_LIMIT_LEFT, window_width - SIDEBAR_LIMIT_RIGHT)
+        / window_width
+}
+
 /// Global launcher configuration stored in
 /// `QuantumLauncher/config.json`.
 ///
@@ -163,9 +183,23 @@ impl LauncherConfig {
     /// resetting the config if it's nonexistent or corrupted
     /// (with an error log message).
     pub fn load_s() -> Result<Self, JsonFileError> {
+        Self::get_or_create().map(|(config, _)| config)
+    }
+
+    /// Same as [`LauncherConfig::load_s`], but also returns
+    /// whether a new config was created because there wasn't
+    /// one before (ie. this is a new user, who should see
+    /// the welcome screen).
+    ///
+    /// Corrupted configs are reset too, but that doesn't
+    /// count as a new user.
+    ///
+    /// # Errors
+    /// - if the user doesn't have permission to access launcher directory
+    pub fn get_or_create() -> Result<(Self, bool), JsonFileError> {
         let config_path = LAUNCHER_DIR.join("config.json");
         if !config_path.exists() {
-            return LauncherConfig::create(&config_path);
+            return Ok((LauncherConfig::create(&config_path)?, true));
         }
 
         let mut config = std::fs::read_to_string(&config_path).path(&config_path)?;
@@ -185,21 +219,24 @@ impl LauncherConfig {
                 );
                 let old_path = LAUNCHER_DIR.join("config.json.bak");
                 _ = std::fs::copy(&config_path, &old_path);
-                return LauncherConfig::create(&config_path);
+                return Ok((LauncherConfig::create(&config_path)?, false));
             }
         };
         config.fix();
 
-        Ok(config)
+        Ok((config, false))
     }
 
-    pub async fn save(&self) -> Result<(), JsonFileError> {
+    /// Saves the config to `QuantumLauncher/config.json`
+    /// without blocking the current thread.
+    ///
+    /// The file is written atomically, so a crash midway
+    /// won't leave a corrupted config behind.
+    pub async fn save_async(&self) -> Result<(), JsonFileError> {
         let config_path = LAUNCHER_DIR.join("config.json");
         let config = serde_json::to_string(&self).json_to()?;
 
-        tokio::fs::write(&config_path, config.as_bytes())
-            .await
-            .path(config_path)?;
+        file_utils::atomic_write(&config_path, config.as_bytes()).await?;
         Ok(())
     }
 
@@ -11,6 +11,7 @@
 
 use crate::stylesheet::styles::LauncherTheme;
 use paste::paste;
+use ql_core::Loader;
 
 const ICON_FONT: iced::Font = iced::Font::with_name("QuantumLauncher");
 
@@ -91,3 +92,18 @@ icon_define!(version_tick, '\u{e931}');
 icon_define!(version_warn, '\u{e932}');
 icon_define!(warn, '\u{e933}');
 icon_define!(win_size, '\u{e934}');
+
+/// The icon shown next to a mod loader's name.
+///
+/// There are no dedicated loader logos in the icon font,
+/// so this picks the closest matching generic icon.
+pub fn loader_s<'a>(loader: Loader, size: u16) -> iced::widget::Text<'a, LauncherTheme> {
+    match loader {
+        Loader::Vanilla => file_jar_s(size),
+        Loader::Fabric | Loader::Quilt => tweak_s(size),
+        Loader::Forge | Loader::NeoForge => gear_s(size),
+        Loader::OptiFine => paintbrush_s(size),
+        Loader::Paper => file_s(size),
+        Loader::Liteloader | Loader::Modloader | Loader::Rift => qm_s(size),
+    }
+}
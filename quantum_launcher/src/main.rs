@@ -91,13 +91,10 @@ mod tick;
 const LAUNCHER_ICON: &[u8] = include_bytes!("../../assets/icon/ql_logo.ico");
 
 impl Launcher {
-    fn new(
-        is_new_user: bool,
-        config: Result<LauncherConfig, JsonFileError>,
-    ) -> (Self, Task<Message>) {
+    fn new(config: Result<(LauncherConfig, bool), JsonFileError>) -> (Self, Task<Message>) {
         #[cfg(feature = "auto_update")]
         let check_for_updates_command = {
-            let should_check = if let Ok(c) = &config {
+            let should_check = if let Ok((c, _)) = &config {
                 c.should_update_check()
             } else {
                 true
@@ -114,14 +111,13 @@ impl Launcher {
         #[cfg(not(feature = "auto_update"))]
         let check_for_updates_command = Task::none();
 
-        let mut launcher =
-            Launcher::load_new(is_new_user, config).unwrap_or_else(Launcher::with_error);
+        let mut launcher = Launcher::load_new(config).unwrap_or_else(Launcher::with_error);
         // let mut launcher = Launcher::with_error("test");
 
         let load_notes_command = if let (Some(instance), State::Launch(menu)) =
             (launcher.selected_instance.clone(), &mut launcher.state)
         {
-            menu.reload_notes(instance)
+            menu.reload_instance_info(instance)
         } else {
             Task::none()
         };
@@ -145,6 +141,9 @@ impl Launcher {
                 Task::perform(ql_core::clean::dir(LAUNCHER_DIR.join("logs")), |n| {
                     Message::CoreCleanComplete(n.strerr())
                 }),
+                Task::perform(ql_core::clean::remove_orphaned_java_installs(), |_| {
+                    Message::Nothing
+                }),
                 CustomJarState::load(),
             ]),
         )
@@ -186,9 +185,6 @@ fn main() {
         do_migration();
     }
 
-    let is_new_user = file_utils::is_new_user();
-    // let is_new_user = true; // Uncomment to test the intro screen.
-
     let (mut launcher_dir, is_dir_err) = load_launcher_dir();
 
     cli::start_cli(is_dir_err, &mut launcher_dir);
@@ -205,7 +201,7 @@ fn main() {
     let icon = load_icon();
     let config = load_config(launcher_dir.is_some());
 
-    let c = config.as_ref().cloned().unwrap_or_default();
+    let c = config.as_ref().map(|(c, _)| c.clone()).unwrap_or_default();
     let decorations = c.uses_system_decorations();
     let (width, height) = c.c_window_size();
 
@@ -220,7 +216,7 @@ fn main() {
             antialiasing: config
                 .as_ref()
                 .ok()
-                .and_then(|n| n.ui_antialiasing)
+                .and_then(|(n, _)| n.ui_antialiasing)
                 .unwrap_or(true),
             ..Default::default()
         })
@@ -241,7 +237,7 @@ fn main() {
             },
             ..Default::default()
         })
-        .run_with(move || Launcher::new(is_new_user, config))
+        .run_with(move || Launcher::new(config))
         .unwrap();
 }
 
@@ -261,8 +257,8 @@ fn load_launcher_dir() -> (Option<std::path::PathBuf>, bool) {
     (launcher_dir, is_dir_err)
 }
 
-fn load_config(dir_is_ok: bool) -> Result<LauncherConfig, JsonFileError> {
-    if let Some(cfg) = dir_is_ok.then(LauncherConfig::load_s) {
+fn load_config(dir_is_ok: bool) -> Result<(LauncherConfig, bool), JsonFileError> {
+    if let Some(cfg) = dir_is_ok.then(LauncherConfig::get_or_create) {
         cfg
     } else {
         Err(JsonFileError::Io(ql_core::IoError::LauncherDirNotFound))
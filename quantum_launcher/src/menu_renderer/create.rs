@@ -11,7 +11,8 @@ use crate::{
     icons,
     menu_renderer::{
         Column, Element, back_to_launch_screen, button_with_icon, ctxbox, dots,
-        launch::import_description, offset, shortcut_ctrl, sidebar_button, tooltip, tsubtitle,
+        launch::import_description, offset, sidebar_button, tooltip, tooltip_with_shortcut,
+        tsubtitle,
     },
     state::{CreateInstanceMessage, MenuCreateInstance, MenuCreateInstanceChoosing, Message},
     stylesheet::{
@@ -337,6 +338,6 @@ fn get_create_button(already_exists: bool) -> widget::Tooltip<'static, Message,
             Position::FollowCursor,
         )
     } else {
-        tooltip(create_button, shortcut_ctrl("Enter"), Position::Bottom)
+        tooltip_with_shortcut(create_button, "Create instance", "Enter", Position::Bottom)
     }
 }
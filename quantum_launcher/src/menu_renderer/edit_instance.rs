@@ -1,9 +1,10 @@
 use crate::{
     icons,
     menu_renderer::{
-        Column, FONT_MONO, button_with_icon, checkered_list, settings::PREFIX_EXPLANATION,
+        Column, FONT_MONO, button_with_icon, checkered_list, settings::PREFIX_EXPLANATION, tooltip,
         tsubtitle,
     },
+    message_update::format_memory_precise,
     state::{
         CustomJarState, EditInstanceMessage, ListMessage, MenuEditInstance, Message, NONE_JAR_NAME,
     },
@@ -11,7 +12,7 @@ use crate::{
 };
 use iced::{
     Alignment, Length,
-    widget::{self, column, horizontal_space, row},
+    widget::{self, column, horizontal_space, row, tooltip::Position},
 };
 use ql_core::{Instance, InstanceKind};
 use ql_core::{
@@ -83,7 +84,7 @@ impl MenuEditInstance {
             .spacing(5),
             widget::text!(
                 "{} {}",
-                self.config.mod_type,
+                self.config.get_mod_loader_display_name(),
                 if selected_instance.is_server() {
                     "Server"
                 } else {
@@ -101,14 +102,27 @@ impl MenuEditInstance {
                     widget::Space::with_height(1),
                     widget::text_input("Rename Instance", &self.state_rename.name)
                         .on_input(|n| EditInstanceMessage::RenameEdit(n).into()),
+                ]
+                .push_maybe(self.state_rename.error.map(|err| {
+                    widget::text(err)
+                        .size(12)
+                        .style(|_: &LauncherTheme| widget::text::Style {
+                            color: Some(iced::Color::from_rgb8(0xE0, 0x5A, 0x5A)),
+                        })
+                }))
+                .push(
                     row![
-                        widget::button(widget::text("Rename").size(12))
-                            .on_press(EditInstanceMessage::RenameApply.into()),
+                        widget::button(widget::text("Rename").size(12)).on_press_maybe(
+                            self.state_rename
+                                .error
+                                .is_none()
+                                .then_some(EditInstanceMessage::RenameApply.into())
+                        ),
                         widget::button(widget::text("Cancel").size(12))
                             .on_press(EditInstanceMessage::RenameToggle.into())
                     ]
-                    .spacing(5)
-                ]
+                    .spacing(5),
+                )
                 .spacing(5),
             ),
         )
@@ -140,6 +154,14 @@ impl MenuEditInstance {
                 EditInstanceMessage::GameArgs(n)
             )),
             sp(),
+            "Extra classpath JARs:",
+            widget::text("Absolute paths, or relative to the instance folder")
+                .size(12)
+                .style(tsubtitle),
+            get_args_list(self.config.classpath_extras.as_deref(), |n| {
+                Message::EditInstance(EditInstanceMessage::ClasspathExtras(n))
+            }),
+            sp(),
             self.item_args_prefix(prefix_mode),
             sp(),
             args_split_by_space(self.arg_split_by_space),
@@ -219,7 +241,11 @@ Heavy modpacks / High settings: 4-8 GB+"
             .style(tsubtitle),
             widget::Space::with_height(5),
             row![
-                widget::text(&self.state_ram.slider_text),
+                tooltip(
+                    widget::text(&self.state_ram.slider_text),
+                    widget::text(format_memory_precise(self.config.ram_in_mb)).size(12),
+                    Position::Top
+                ),
                 widget::slider(
                     MEM_256_MB_IN_TWOS_EXPONENT..=mem_max_in_twos_exponent,
                     self.state_ram.slider_value,
@@ -2,7 +2,7 @@ use cfg_if::cfg_if;
 use frostmark::MarkWidget;
 use iced::widget::{column, horizontal_space, row, text_editor, tooltip::Position, vertical_space};
 use iced::{Alignment, Length, Padding, widget};
-use ql_core::{Instance, InstanceKind, LAUNCHER_VERSION_NAME};
+use ql_core::{Instance, InstanceKind, LAUNCHER_VERSION_NAME, json::InstanceConfigJson};
 
 use crate::cli::EXPERIMENTAL_MMC_IMPORT;
 use crate::menu_renderer::onboarding::x86_warning;
@@ -10,8 +10,9 @@ use crate::menu_renderer::{
     CTXI_SIZE, Column, FONT_MONO, barthin, ctx_button_icon, ctxbox, sidebar, tsubtitle, underline,
     view_info_message,
 };
+use crate::message_update::format_memory_bytes;
 use crate::state::{
-    GameLogMessage, InstanceNotes, LaunchMessage, LaunchModal, LauncherSettingsTab,
+    GameLogMessage, GameProcess, InstanceNotes, LaunchMessage, LaunchModal, LauncherSettingsTab,
     MainMenuMessage, NotesMessage, ShortcutMessage, SidebarMessage, SidebarScroll, WindowMessage,
 };
 use crate::{
@@ -25,7 +26,7 @@ use crate::{
     stylesheet::{color::Color, styles::LauncherTheme, widgets::StyleButton},
 };
 
-use super::{Element, button_with_icon, shortcut_ctrl, tooltip};
+use super::{Element, button_with_icon, tooltip, tooltip_with_shortcut};
 
 pub const TAB_BUTTON_WIDTH: f32 = 64.0;
 
@@ -214,8 +215,26 @@ impl Launcher {
             }
         };
 
+        let title: Element = if let Some((properties, config)) = menu
+            .properties
+            .as_ref()
+            .and_then(|p| Some((p, p.config.as_ref()?)))
+        {
+            instance_properties_panel(
+                selected.get_name(),
+                config,
+                properties.version.as_deref(),
+                properties.disk_usage,
+            )
+        } else {
+            widget::text(selected.get_name())
+                .font(FONT_MONO)
+                .size(20)
+                .into()
+        };
+
         column![
-            row![widget::text(selected.get_name()).font(FONT_MONO).size(20)]
+            row![title]
                 .push_maybe(is_running.then_some(icons::play_s(20)))
                 .push_maybe(
                     is_running.then_some(
@@ -280,6 +299,7 @@ impl Launcher {
             log: log_data,
             has_crashed,
             command,
+            is_truncated,
         }) = self
             .selected_instance
             .as_ref()
@@ -305,17 +325,55 @@ impl Launcher {
                 ),
                 small_button("Join Discord").on_press(Message::CoreOpenLink(DISCORD.to_owned())),
                 widget::horizontal_space(),
+                widget::text_input(
+                    "Search log...",
+                    menu.log_search.as_deref().unwrap_or_default()
+                )
+                .size(12)
+                .padding([4, 8])
+                .width(160)
+                .on_input(|n| GameLogMessage::Search(Some(n)).into()),
+            ]
+            .push_maybe(menu.log_search.as_ref().map(|query| {
+                widget::text!(
+                    "{} match{}",
+                    menu.search_log_results.len(),
+                    if menu.search_log_results.len() == 1 {
+                        ""
+                    } else {
+                        "es"
+                    }
+                )
+                .size(12)
+                .style(move |t: &LauncherTheme| {
+                    t.style_text(if query.is_empty() || !menu.search_log_results.is_empty() {
+                        Color::Mid
+                    } else {
+                        Color::Dark
+                    })
+                })
+            }))
+            .push_maybe(menu.log_search.is_some().then(|| {
+                widget::button(icons::close().size(12))
+                    .padding(0)
+                    .style(|t: &LauncherTheme, s| t.style_button(s, StyleButton::FlatDark))
+                    .on_press(GameLogMessage::Search(None).into())
+            }))
+            .push(
                 widget::mouse_area(widget::container(icons::arrow_up_s(12))).on_press(
                     GameLogMessage::Action(text_editor::Action::Move(text_editor::Motion::PageUp))
-                        .into()
+                        .into(),
                 ),
+            )
+            .push(
                 widget::mouse_area(widget::container(icons::arrow_down_s(12))).on_press(
                     Message::GameLog(GameLogMessage::Action(text_editor::Action::Move(
-                        text_editor::Motion::PageDown
-                    )))
+                        text_editor::Motion::PageDown,
+                    ))),
                 ),
-            ]
-            .spacing(7),
+            )
+            .spacing(7)
+            .align_y(Alignment::Center),
             widget::text(" Having issues? Copy and send the game log for support").size(12)
         ]
         .push_maybe(
@@ -330,6 +388,11 @@ impl Launcher {
                 .size(18),
             ),
         )
+        .push_maybe(is_truncated.then(|| {
+            widget::text("Log truncated at 10,000 lines")
+                .size(12)
+                .style(|t: &LauncherTheme| t.style_text(Color::Mid))
+        }))
         .push_maybe(
             matches!(kind, InstanceKind::Server).then_some(
                 widget::text_input("Enter command...", command)
@@ -425,7 +488,9 @@ impl Launcher {
     }
 
     fn is_process_running(&self, instance: &Instance) -> bool {
-        self.processes.contains_key(instance)
+        self.processes
+            .get(instance)
+            .is_some_and(GameProcess::is_alive)
     }
 
     fn get_accounts_bar(&self, menu: &MenuLaunch) -> Element<'_> {
@@ -446,15 +511,24 @@ impl Launcher {
         };
 
         column![
-            row![widget::text(" Accounts:").size(14), horizontal_space()].push_maybe(
-                (self.account_selected != OFFLINE_ACCOUNT_NAME).then_some(
-                    widget::button(widget::text("Logout").size(11))
+            row![widget::text(" Accounts:").size(14), horizontal_space()]
+                .push_maybe(self.accounts.get(&self.account_selected).map(|account| {
+                    widget::button(widget::text("Skin").size(11))
                         .padding(3)
-                        .on_press(AccountMessage::LogoutCheck.into())
-                        .style(|n: &LauncherTheme, status| n
-                            .style_button(status, StyleButton::FlatExtraDark))
-                )
-            ),
+                        .on_press(Message::CoreOpenLink(account.get_skin_service_url()))
+                        .style(|n: &LauncherTheme, status| {
+                            n.style_button(status, StyleButton::FlatExtraDark)
+                        })
+                }))
+                .push_maybe(
+                    (self.account_selected != OFFLINE_ACCOUNT_NAME).then_some(
+                        widget::button(widget::text("Logout").size(11))
+                            .padding(3)
+                            .on_press(AccountMessage::LogoutCheck.into())
+                            .style(|n: &LauncherTheme, status| n
+                                .style_button(status, StyleButton::FlatExtraDark))
+                    )
+                ),
             dropdown
         ]
         .push_maybe(
@@ -480,12 +554,13 @@ impl Launcher {
             tooltip(play_button, "Username is empty!", Position::Bottom)
         } else if self.config.username.contains(' ') && is_offline {
             tooltip(play_button, "Username contains spaces!", Position::Bottom)
-        } else if self.processes.contains_key(selected) {
-            tooltip(
+        } else if self.is_process_running(selected) {
+            tooltip_with_shortcut(
                 button_with_icon(icons::play(), "Kill", 16)
                     .on_press(LaunchMessage::Kill.into())
                     .width(98),
-                shortcut_ctrl("Backspace"),
+                "Kill game",
+                "Backspace",
                 Position::Bottom,
             )
         } else if self.is_launching_game {
@@ -495,9 +570,10 @@ impl Launcher {
                 Position::Bottom,
             )
         } else {
-            tooltip(
+            tooltip_with_shortcut(
                 play_button.on_press(LaunchMessage::Start.into()),
-                shortcut_ctrl("Enter"),
+                "Launch game",
+                "Enter",
                 Position::Bottom,
             )
         }
@@ -517,12 +593,13 @@ impl Launcher {
         &self,
         selected: &Instance,
     ) -> widget::Tooltip<'_, Message, LauncherTheme> {
-        if self.processes.contains_key(selected) {
-            tooltip(
+        if self.is_process_running(selected) {
+            tooltip_with_shortcut(
                 button_with_icon(icons::play(), "Stop", 16)
                     .width(98)
                     .on_press(LaunchMessage::Kill.into()),
-                shortcut_ctrl("Escape"),
+                "Stop server",
+                "Escape",
                 Position::Bottom,
             )
         } else if self.is_launching_game {
@@ -643,6 +720,32 @@ fn render_tab_button(tab: LaunchTab, decor: bool, menu: &'_ MenuLaunch) -> Eleme
     }
 }
 
+/// The instance name, with a compact line of info
+/// (version, loader, disk usage) below it.
+///
+/// `version` and `disk_usage` show as `...` if they couldn't be loaded.
+fn instance_properties_panel<'a>(
+    name: &'a str,
+    config: &InstanceConfigJson,
+    version: Option<&str>,
+    disk_usage: Option<u64>,
+) -> Element<'a> {
+    let version = version.unwrap_or("...");
+    let disk_usage = disk_usage.map_or_else(|| "...".to_owned(), format_memory_bytes);
+
+    column![
+        widget::text(name).font(FONT_MONO).size(20),
+        widget::text!(
+            "{version}  |  {}  |  {disk_usage}",
+            config.get_mod_loader_display_name()
+        )
+        .size(12)
+        .style(tsubtitle),
+    ]
+    .spacing(2)
+    .into()
+}
+
 fn get_no_logs_message<'a>() -> Column<'a> {
     const BASE_MESSAGE: &str = "No logs found";
 
@@ -2,7 +2,7 @@ use iced::{
     Alignment, Length,
     widget::{self, column, row, tooltip::Position},
 };
-use ql_core::Progress;
+use ql_core::{MultiProgress, Progress};
 use ql_instances::auth::AccountType;
 
 use crate::{
@@ -135,14 +135,24 @@ fn view_info_message(
 
 pub fn checkered_list<'a, Item: Into<Element<'a>>>(
     children: impl IntoIterator<Item = Item>,
+) -> Column<'a> {
+    checkered_list_focused(children, None)
+}
+
+/// A [`checkered_list`] where the item at `focused_index`
+/// (if any) is outlined, for keyboard navigation.
+pub fn checkered_list_focused<'a, Item: Into<Element<'a>>>(
+    children: impl IntoIterator<Item = Item>,
+    focused_index: Option<usize>,
 ) -> Column<'a> {
     widget::column(children.into_iter().enumerate().map(|(i, e)| {
+        let is_focused = focused_index == Some(i);
         widget::container(e)
             .width(Length::Fill)
             .padding(16)
             .style(move |t: &LauncherTheme| {
                 t.style_container_sharp_box(
-                    0.0,
+                    if is_focused { 1.0 } else { 0.0 },
                     if i % 2 == 0 {
                         Color::Dark
                     } else {
@@ -272,12 +282,31 @@ pub fn button_with_icon<'a>(
     .padding([6, 12])
 }
 
-#[allow(unreachable_code)]
-pub fn shortcut_ctrl<'a>(key: &str) -> Element<'a> {
-    #[cfg(target_os = "macos")]
-    return widget::text!("Command + {key}").size(12).into();
+/// A keyboard shortcut using the Control key
+/// (Command on macOS), eg. `Control + Enter`.
+fn shortcut_ctrl(key: &str) -> String {
+    if cfg!(target_os = "macos") {
+        format!("Command + {key}")
+    } else {
+        format!("Control + {key}")
+    }
+}
 
-    widget::text!("Control + {key}").size(12).into()
+/// A [`tooltip`] describing what `e` does,
+/// followed by its Control/Command shortcut.
+///
+/// Eg: `Play (Control + Enter)`
+pub fn tooltip_with_shortcut<'a>(
+    e: impl Into<Element<'a>>,
+    text: &str,
+    key: &str,
+    position: Position,
+) -> widget::Tooltip<'a, Message, LauncherTheme> {
+    tooltip(
+        e,
+        widget::text!("{text} ({})", shortcut_ctrl(key)).size(12),
+        position,
+    )
 }
 
 fn sidebar_button<'a, A: PartialEq>(
@@ -416,12 +445,28 @@ pub fn back_to_launch_screen(message: Option<InfoMessage>) -> Message {
 impl<T: Progress> ProgressBar<T> {
     pub fn view(&'_ self) -> Column<'_> {
         let total = T::total();
-        column![widget::progress_bar(0.0..=total, self.num)]
+        let bar = row![widget::progress_bar(0.0..=total, self.num)]
+            .push_maybe(self.cancel_message.clone().map(|msg| {
+                widget::button(icons::close_s(12))
+                    .style(|t: &LauncherTheme, s| t.style_button(s, StyleButton::RoundDark))
+                    .padding([4, 8])
+                    .on_press(msg)
+            }))
+            .align_y(Alignment::Center)
+            .spacing(5);
+
+        column![bar]
             .push_maybe(self.message.as_deref().map(widget::text))
             .spacing(10)
     }
 }
 
+pub fn view_multi_progress<T: Progress>(progress: &'_ MultiProgress<T>) -> Column<'_> {
+    column![widget::progress_bar(0.0..=T::total(), progress.get_num())]
+        .push_maybe(progress.get_message().map(widget::text))
+        .spacing(10)
+}
+
 impl MenuCurseforgeManualDownload {
     pub fn view(&'_ self) -> Element<'_> {
         column![
@@ -2,8 +2,8 @@ use crate::{
     icons,
     menu_renderer::{
         CTXI_SIZE, Column, Element, back_to_launch_screen, barthin, button_with_icon, ctx_button,
-        ctx_button_empty, ctx_button_icon, dots, offsetbox, subbutton_with_icon, tsubtitle,
-        view_info_message,
+        ctx_button_empty, ctx_button_icon, dots, offsetbox, subbutton_with_icon, tooltip,
+        tsubtitle, view_info_message,
     },
     message_handler::ForgeKind,
     state::{
@@ -16,10 +16,10 @@ use crate::{
 };
 use iced::{
     Alignment, Length,
-    widget::{self, column, row},
+    widget::{self, column, row, tooltip::Position},
 };
 use ql_core::{Instance, InstanceKind, Loader, json::InstanceConfigJson};
-use ql_mod_manager::store::QueryType;
+use ql_mod_manager::{loaders, store::QueryType};
 
 pub const MODS_SIDEBAR_WIDTH: u16 = 190;
 
@@ -223,6 +223,12 @@ impl MenuEditMods {
                     add_file_btn,
                 ]
                 .spacing(5),
+                row![
+                    icons::loader_s(self.file_data.config.mod_type, 14),
+                    widget::text(self.file_data.config.get_mod_loader_display_name()).size(14),
+                ]
+                .align_y(Alignment::Center)
+                .spacing(8),
                 self.get_mod_installer_buttons(selected_instance.kind),
                 column![
                     button_with_icon(icons::download_s(15), "Download Content...", 14)
@@ -283,40 +289,95 @@ impl MenuEditMods {
     }
 
     fn get_mod_installer_buttons(&'_ self, kind: InstanceKind) -> Element<'_> {
+        let forge_supported = self.file_data.details.supports_forge();
+        let forge_unsupported_msg = (!forge_supported).then(|| {
+            widget::text("Forge is not available for this version")
+                .size(12)
+                .style(tsubtitle)
+        });
+        let compatible = self
+            .file_data
+            .details
+            .compatible_loaders()
+            .into_iter()
+            .filter(|n| kind == InstanceKind::Server || *n != Loader::Paper)
+            .map(|n| n.to_string())
+            .collect::<Vec<_>>();
+        let compatible_msg = widget::text(if compatible.is_empty() {
+            "No known loaders for this version".to_owned()
+        } else {
+            format!("Supported: {}", compatible.join(", "))
+        })
+        .size(12)
+        .style(tsubtitle);
+        let version = self.file_data.details.get_id();
+
         match self.file_data.config.mod_type {
             Loader::Vanilla => match kind {
                 InstanceKind::Client => column![
                     "Install:",
                     row![
-                        install_ldr("Fabric")
-                            .on_press(InstallFabricMessage::ScreenOpen { is_quilt: false }.into()),
-                        install_ldr("Quilt")
-                            .on_press(InstallFabricMessage::ScreenOpen { is_quilt: true }.into()),
+                        install_ldr(
+                            Loader::Fabric,
+                            version,
+                            Some(InstallFabricMessage::ScreenOpen { is_quilt: false }.into())
+                        ),
+                        install_ldr(
+                            Loader::Quilt,
+                            version,
+                            Some(InstallFabricMessage::ScreenOpen { is_quilt: true }.into())
+                        ),
                     ]
                     .spacing(5),
                     row![
-                        install_ldr("Forge").on_press(Message::InstallForge(ForgeKind::Normal)),
-                        install_ldr("NeoForge")
-                            .on_press(Message::InstallForge(ForgeKind::NeoForge))
+                        install_ldr(
+                            Loader::Forge,
+                            version,
+                            forge_supported.then_some(Message::InstallForge(ForgeKind::Normal))
+                        ),
+                        install_ldr(
+                            Loader::NeoForge,
+                            version,
+                            Some(Message::InstallForge(ForgeKind::NeoForge))
+                        )
                     ]
                     .spacing(5),
-                    install_ldr("OptiFine").on_press(InstallOptifineMessage::ScreenOpen.into())
+                    install_ldr(
+                        Loader::OptiFine,
+                        version,
+                        Some(InstallOptifineMessage::ScreenOpen.into())
+                    )
                 ]
+                .push_maybe(forge_unsupported_msg)
+                .push(compatible_msg)
                 .spacing(5)
                 .into(),
                 InstanceKind::Server => column![
                     "Install:",
                     row![
-                        install_ldr("Fabric")
-                            .on_press(InstallFabricMessage::ScreenOpen { is_quilt: false }.into()),
-                        install_ldr("Quilt")
-                            .on_press(InstallFabricMessage::ScreenOpen { is_quilt: true }.into()),
+                        install_ldr(
+                            Loader::Fabric,
+                            version,
+                            Some(InstallFabricMessage::ScreenOpen { is_quilt: false }.into())
+                        ),
+                        install_ldr(
+                            Loader::Quilt,
+                            version,
+                            Some(InstallFabricMessage::ScreenOpen { is_quilt: true }.into())
+                        ),
                     ]
                     .spacing(5),
                     row![
-                        install_ldr("Forge").on_press(Message::InstallForge(ForgeKind::Normal)),
-                        install_ldr("NeoForge")
-                            .on_press(Message::InstallForge(ForgeKind::NeoForge))
+                        install_ldr(
+                            Loader::Forge,
+                            version,
+                            forge_supported.then_some(Message::InstallForge(ForgeKind::Normal))
+                        ),
+                        install_ldr(
+                            Loader::NeoForge,
+                            version,
+                            Some(Message::InstallForge(ForgeKind::NeoForge))
+                        )
                     ]
                     .spacing(5),
                     row![
@@ -324,9 +385,14 @@ impl MenuEditMods {
                         widget::button("Spigot").width(97)
                     ]
                     .spacing(5),
-                    install_ldr("Paper")
-                        .on_press(Message::InstallPaper(InstallPaperMessage::ScreenOpen)),
+                    install_ldr(
+                        Loader::Paper,
+                        version,
+                        Some(Message::InstallPaper(InstallPaperMessage::ScreenOpen))
+                    ),
                 ]
+                .push_maybe(forge_unsupported_msg)
+                .push(compatible_msg)
                 .spacing(5)
                 .into(),
             },
@@ -591,6 +657,18 @@ impl MenuEditMods {
     }
 }
 
-fn install_ldr(loader: &str) -> widget::Button<'_, Message, LauncherTheme> {
-    widget::button(widget::text(loader).size(14)).width(90)
+fn install_ldr<'a>(loader: Loader, version: &str, message: Option<Message>) -> Element<'a> {
+    let button = widget::button(widget::text(loader.to_string()).size(14))
+        .width(90)
+        .on_press_maybe(message);
+    if let Some(size) = loaders::get_loader_install_size_estimate(loader, version) {
+        tooltip(
+            button,
+            widget::text!("~{} MB download", size / (1024 * 1024)).size(12),
+            Position::Bottom,
+        )
+        .into()
+    } else {
+        button.into()
+    }
 }
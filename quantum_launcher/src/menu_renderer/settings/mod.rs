@@ -5,7 +5,10 @@ use iced::{
     widget::{self, column, row},
 };
 
-use super::{Element, back_button, back_to_launch_screen, sidebar, sidebar_button};
+use super::{
+    Column, Element, back_button, back_to_launch_screen, checkered_list_focused, sidebar,
+    sidebar_button,
+};
 use crate::{
     config::LauncherConfig,
     icons,
@@ -104,13 +107,36 @@ pub fn get_theme_selector() -> widget::Row<'static, Message, LauncherTheme> {
     .spacing(5)
 }
 
+/// A row in a settings tab's list.
+struct SettingsRow<'a> {
+    view: Column<'a>,
+    /// What `Enter` does when the row has keyboard focus
+    /// (see [`MenuLauncherSettings::focused_index`]).
+    action: Option<Message>,
+}
+
+impl<'a> SettingsRow<'a> {
+    fn new(view: Column<'a>, action: impl Into<Message>) -> Self {
+        Self {
+            view,
+            action: Some(action.into()),
+        }
+    }
+}
+
+impl<'a> From<Column<'a>> for SettingsRow<'a> {
+    fn from(view: Column<'a>) -> Self {
+        Self { view, action: None }
+    }
+}
+
 impl LauncherSettingsTab {
-    fn view<'a>(
-        &'a self,
+    fn rows<'a>(
+        &self,
         config: &'a LauncherConfig,
         menu: &'a MenuLauncherSettings,
         discord_connection_state: &Mutex<PresenceConnectionState>,
-    ) -> Element<'a> {
+    ) -> Vec<SettingsRow<'a>> {
         match self {
             LauncherSettingsTab::UserInterface => menu.view_ui_tab(config),
             LauncherSettingsTab::Presence => {
@@ -118,8 +144,39 @@ impl LauncherSettingsTab {
             }
             LauncherSettingsTab::Launcher => menu.view_launcher_tab(config),
             LauncherSettingsTab::Game => menu.view_game_tab(config),
-            LauncherSettingsTab::About => tab_about::view(),
+            LauncherSettingsTab::About => Vec::new(),
+        }
+    }
+
+    /// The `Enter` action of each row in this tab's list,
+    /// for keyboard navigation.
+    pub fn row_actions(
+        &self,
+        config: &LauncherConfig,
+        menu: &MenuLauncherSettings,
+        discord_connection_state: &Mutex<PresenceConnectionState>,
+    ) -> Vec<Option<Message>> {
+        self.rows(config, menu, discord_connection_state)
+            .into_iter()
+            .map(|row| row.action)
+            .collect()
+    }
+
+    fn view<'a>(
+        &'a self,
+        config: &'a LauncherConfig,
+        menu: &'a MenuLauncherSettings,
+        discord_connection_state: &Mutex<PresenceConnectionState>,
+    ) -> Element<'a> {
+        if let LauncherSettingsTab::About = self {
+            return tab_about::view().into();
         }
+        checkered_list_focused(
+            self.rows(config, menu, discord_connection_state)
+                .into_iter()
+                .map(|row| row.view),
+            menu.focused_index,
+        )
         .into()
     }
 }
@@ -8,26 +8,38 @@ use crate::{
     config::{AfterLaunchBehavior, LauncherConfig},
     icons,
     menu_renderer::{
-        Column, button_with_icon, checkered_list,
+        Column, button_with_icon,
         edit_instance::{args_split_by_space, get_args_list, resolution_dialog},
-        settings::PREFIX_EXPLANATION,
+        settings::{PREFIX_EXPLANATION, SettingsRow},
         tsubtitle,
     },
     state::{LauncherSettingsMessage, MenuLauncherSettings, Message},
 };
 
 impl MenuLauncherSettings {
-    pub(super) fn view_game_tab<'a>(&'a self, config: &'a LauncherConfig) -> Column<'a> {
-        checkered_list([
-            column![row![
-                widget::text("Game").size(20).width(Length::Fill),
-                button_with_icon(icons::folder_s(14), "Open Launcher Folder", 14)
-                    .on_press_with(|| Message::CoreOpenPath(LAUNCHER_DIR.clone())),
-            ]],
-            opt_changelog(config),
-            opt_after_launch(config),
-            opt_resolution(config),
-            opt_java_args(config),
+    pub(super) fn view_game_tab<'a>(&'a self, config: &'a LauncherConfig) -> Vec<SettingsRow<'a>> {
+        let write_changelog = config
+            .persistent
+            .clone()
+            .unwrap_or_default()
+            .write_mod_update_changelog;
+
+        vec![
+            SettingsRow::new(
+                column![row![
+                    widget::text("Game").size(20).width(Length::Fill),
+                    button_with_icon(icons::folder_s(14), "Open Launcher Folder", 14)
+                        .on_press_with(|| Message::CoreOpenPath(LAUNCHER_DIR.clone())),
+                ]],
+                Message::CoreOpenPath(LAUNCHER_DIR.clone()),
+            ),
+            SettingsRow::new(
+                opt_changelog(write_changelog),
+                LauncherSettingsMessage::ToggleModUpdateChangelog(!write_changelog),
+            ),
+            opt_after_launch(config).into(),
+            opt_resolution(config).into(),
+            opt_java_args(config).into(),
             column![
                 "Global Pre-Launch Prefix:",
                 widget::text(PREFIX_EXPLANATION).size(12).style(tsubtitle),
@@ -40,8 +52,9 @@ impl MenuLauncherSettings {
                 ),
                 args_split_by_space(self.arg_split_by_space),
             ]
-            .spacing(10),
-        ])
+            .spacing(10)
+            .into(),
+        ]
     }
 }
 
@@ -89,17 +102,10 @@ fn opt_after_launch(config: &LauncherConfig) -> Column<'_> {
     ]
 }
 
-fn opt_changelog(config: &LauncherConfig) -> Column<'_> {
+fn opt_changelog(write_changelog: bool) -> Column<'static> {
     column![
-        widget::checkbox(
-            "Write changelog after mod updates",
-            config
-                .persistent
-                .clone()
-                .unwrap_or_default()
-                .write_mod_update_changelog,
-        )
-        .on_toggle(|n| LauncherSettingsMessage::ToggleModUpdateChangelog(n).into()),
+        widget::checkbox("Write changelog after mod updates", write_changelog)
+            .on_toggle(|n| LauncherSettingsMessage::ToggleModUpdateChangelog(n).into()),
         widget::text("Writes mod update changes to .minecraft/changelogs")
             .size(12)
             .style(tsubtitle),
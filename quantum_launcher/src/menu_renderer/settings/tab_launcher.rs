@@ -7,23 +7,32 @@ use ql_core::LAUNCHER_DIR;
 use crate::{
     config::LauncherConfig,
     icons,
-    menu_renderer::{Column, button_with_icon, checkered_list, tooltip, tsubtitle},
+    menu_renderer::{Column, button_with_icon, settings::SettingsRow, tooltip, tsubtitle},
     state::{LauncherSettingsMessage, MenuLauncherSettings, Message, SettingsOutmsg},
 };
 
 impl MenuLauncherSettings {
-    pub(super) fn view_launcher_tab<'a>(&'a self, config: &'a LauncherConfig) -> Column<'a> {
-        checkered_list([
-            column![row![
-                widget::text("Launcher Settings")
-                    .size(20)
-                    .width(Length::Fill),
-                widget::horizontal_space(),
-                button_with_icon(icons::folder_s(14), "Open Launcher Folder", 14)
-                    .padding([5, 10])
-                    .on_press_with(|| Message::CoreOpenPath(LAUNCHER_DIR.clone())),
-            ]],
-            self.opt_caching(config),
+    pub(super) fn view_launcher_tab<'a>(
+        &'a self,
+        config: &'a LauncherConfig,
+    ) -> Vec<SettingsRow<'a>> {
+        vec![
+            SettingsRow::new(
+                column![row![
+                    widget::text("Launcher Settings")
+                        .size(20)
+                        .width(Length::Fill),
+                    widget::horizontal_space(),
+                    button_with_icon(icons::folder_s(14), "Open Launcher Folder", 14)
+                        .padding([5, 10])
+                        .on_press_with(|| Message::CoreOpenPath(LAUNCHER_DIR.clone())),
+                ]],
+                Message::CoreOpenPath(LAUNCHER_DIR.clone()),
+            ),
+            SettingsRow::new(
+                self.opt_caching(config),
+                LauncherSettingsMessage::ToggleCaching(!config.do_cache),
+            ),
             column![
                 row![
                     button_with_icon(icons::bin_s(12), "Clean unused assets", 12)
@@ -38,6 +47,19 @@ impl MenuLauncherSettings {
                 )
                 .align_y(Alignment::Center)
                 .spacing(10),
+                row![
+                    button_with_icon(icons::bin_s(12), "Remove broken instances", 12)
+                        .padding([5, 10])
+                        .on_press(LauncherSettingsMessage::RemoveOrphanedInstances.into()),
+                ]
+                .push_maybe(
+                    self.outmsg
+                        .as_ref()
+                        .filter(|_| matches!(self.outmsg_at, SettingsOutmsg::OrphanedInstances))
+                        .map(|msg| widget::text(msg).size(14))
+                )
+                .align_y(Alignment::Center)
+                .spacing(10),
                 row![
                     button_with_icon(icons::bin_s(12), "Clear Java installs", 12)
                         .padding([5, 10])
@@ -50,9 +72,25 @@ impl MenuLauncherSettings {
                 ]
                 .spacing(10)
                 .wrap(),
+                row![
+                    widget::button(widget::text("Check available Java").size(12))
+                        .padding([5, 10])
+                        .on_press(LauncherSettingsMessage::CheckJavaVersions.into()),
+                ]
+                .push_maybe(
+                    self.outmsg
+                        .as_ref()
+                        .filter(|_| matches!(self.outmsg_at, SettingsOutmsg::JavaVersions))
+                        .map(
+                            |msg| widget::text!("Java versions for your platform: {msg}").size(14)
+                        )
+                )
+                .align_y(Alignment::Center)
+                .spacing(10),
             ]
-            .spacing(16),
-        ])
+            .spacing(16)
+            .into(),
+        ]
     }
 
     fn opt_caching(&self, config: &LauncherConfig) -> Column<'_> {
@@ -11,7 +11,7 @@ use crate::{
         discord_rpc::{PresenceStatusDisplayType, RpcConfig, RpcText},
     },
     icons,
-    menu_renderer::{Column, FONT_MONO, button_with_icon, checkered_list, tsubtitle},
+    menu_renderer::{Column, FONT_MONO, button_with_icon, settings::SettingsRow, tsubtitle},
     message_update::PresenceConnectionState,
     state::{MenuLauncherSettings, Message, RpcInnerMessage, RpcMessage},
     stylesheet::{styles::LauncherTheme, widgets::StyleButton},
@@ -22,7 +22,7 @@ impl MenuLauncherSettings {
         &'a self,
         config: &'a LauncherConfig,
         discord_connection_state: &Mutex<PresenceConnectionState>,
-    ) -> Column<'a> {
+    ) -> Vec<SettingsRow<'a>> {
         let rpc_config = config.discord_rpc.clone().unwrap_or_default();
         let presence_state = discord_connection_state.lock().unwrap();
 
@@ -34,16 +34,18 @@ impl MenuLauncherSettings {
             ]
         };
 
-        checkered_list([
+        vec![
+            // No Enter action: resetting is destructive,
+            // so it should only happen through the button
             column![
                 row![
                     widget::text("Discord Rich Presence").size(20).width(Length::Fill),
                     button_with_icon(icons::refresh_s(14), "Reset to Defaults", 14)
                         .on_press_with(|| RpcMessage::ResetPresence.into()),
                 ]
-            ],
+            ].into(),
 
-            column![
+            SettingsRow::new(column![
                 widget::checkbox("Enable Broadcast", rpc_config.enable)
                     .on_toggle(|n| RpcMessage::Toggle(n).into()),
                 widget::text("Sometimes toggling this option might take some time to apply the activity updates on Discord.").size(12).style(tsubtitle),
@@ -67,32 +69,34 @@ impl MenuLauncherSettings {
                     },
                 },
             ]
-            .spacing(5),
-
-            column![
-                widget::text("Core Settings:"),
-                widget::text("Tweak initial/custom presence, add flavor, change names, let your imagination fly.").size(12).style(tsubtitle),
-                widget::Space::with_height(6),
+            .spacing(5), RpcMessage::Toggle(!rpc_config.enable)),
 
-                column![
-                    rpc_config.basic.view(&format!("{} Presence", if rpc_config.update_on_game_open {"Startup"} else {"Custom"}), RpcMessage::DefaultChanged),
-                    if rpc_config.enable {
-                        column![
-                            button_with_icon(icons::discord_s(16), "Set Now", 12)
-                                .padding([5, 10])
-                                .on_press(RpcMessage::SetPresenceNow.into()),
-                            widget::text("Changes will take effect on launcher restart or with the press of the button above.").size(12).style(tsubtitle),
-                        ].spacing(5)
-                    } else {
-                        column![
-                            widget::text("Toggle 'Enable Broadcast' to actually start using presences.").size(12).style(tsubtitle),
-                        ]
-                    },
-                ].spacing(20),
-            ].spacing(5),
+            SettingsRow {
+                view: column![
+                    widget::text("Core Settings:"),
+                    widget::text("Tweak initial/custom presence, add flavor, change names, let your imagination fly.").size(12).style(tsubtitle),
+                    widget::Space::with_height(6),
 
+                    column![
+                        rpc_config.basic.view(&format!("{} Presence", if rpc_config.update_on_game_open {"Startup"} else {"Custom"}), RpcMessage::DefaultChanged),
+                        if rpc_config.enable {
+                            column![
+                                button_with_icon(icons::discord_s(16), "Set Now", 12)
+                                    .padding([5, 10])
+                                    .on_press(RpcMessage::SetPresenceNow.into()),
+                                widget::text("Changes will take effect on launcher restart or with the press of the button above.").size(12).style(tsubtitle),
+                            ].spacing(5)
+                        } else {
+                            column![
+                                widget::text("Toggle 'Enable Broadcast' to actually start using presences.").size(12).style(tsubtitle),
+                            ]
+                        },
+                    ].spacing(20),
+                ].spacing(5),
+                action: rpc_config.enable.then(|| RpcMessage::SetPresenceNow.into()),
+            },
 
-            column![
+            SettingsRow::new(column![
                 widget::text("Toggles:"),
                 widget::Space::with_height(5),
                 widget::checkbox("Change presence during play/quit events", rpc_config.update_on_game_open)
@@ -106,7 +110,7 @@ impl MenuLauncherSettings {
                     icons::paintbrush_s(15),
                 ],
                 widget::text("A fancier way to show off your activities. Try this at home!").size(12).style(tsubtitle),
-            ].spacing(5),
+            ].spacing(5), RpcMessage::TogglePresenceOnGameEvent(!rpc_config.update_on_game_open)),
 
             if rpc_config.update_on_game_open {
                 column![
@@ -122,7 +126,7 @@ impl MenuLauncherSettings {
                 ].spacing(5)
             } else {
                 column![]
-            },
+            }.into(),
 
             column![
                 widget::text("App/Activity Name"),
@@ -136,8 +140,8 @@ impl MenuLauncherSettings {
                 widget::text("Choose which one to display in your profile banner as status:").size(12).style(tsubtitle),
                 widget::Space::with_height(5),
                 get_sdt_selector(&rpc_config)
-            ].spacing(5),
-        ])
+            ].spacing(5).into(),
+        ]
     }
 }
 
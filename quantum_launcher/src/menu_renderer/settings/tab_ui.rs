@@ -6,7 +6,9 @@ use iced::{
 use crate::{
     config::LauncherConfig,
     menu_renderer::{
-        Column, checkered_list, get_mode_selector, settings::get_theme_selector, tsubtitle,
+        get_mode_selector,
+        settings::{SettingsRow, get_theme_selector},
+        tsubtitle,
     },
     state::{LauncherSettingsMessage, MenuLauncherSettings, Message},
     stylesheet::styles::LauncherTheme,
@@ -15,7 +17,7 @@ use crate::{
 const SETTING_WIDTH: u16 = 180;
 
 impl MenuLauncherSettings {
-    pub(super) fn view_ui_tab<'a>(&'a self, config: &'a LauncherConfig) -> Column<'a> {
+    pub(super) fn view_ui_tab<'a>(&'a self, config: &'a LauncherConfig) -> Vec<SettingsRow<'a>> {
         let ui_scale_apply = row![
             widget::horizontal_space(),
             widget::button(widget::text("Apply").size(12))
@@ -24,9 +26,23 @@ impl MenuLauncherSettings {
         ];
 
         let idle_fps = config.c_idle_fps();
+        let scale_changed = (self.temp_scale - config.ui_scale.unwrap_or(1.0)).abs() > 0.01;
 
-        checkered_list([
-            column![widget::text("User Interface").size(20)],
+        let ui_scale = column![row![
+            row![widget::text!("UI Scale ({:.2}x)  ", self.temp_scale).size(15)]
+                .push_maybe(scale_changed.then_some(ui_scale_apply))
+                .align_y(Alignment::Center)
+                .width(SETTING_WIDTH),
+            widget::slider(0.5..=3.0, self.temp_scale, |n| {
+                LauncherSettingsMessage::UiScale(n).into()
+            })
+            .step(0.1),
+        ]
+        .align_y(Alignment::Center)
+        .spacing(5)];
+
+        vec![
+            column![widget::text("User Interface").size(20)].into(),
 
             column![
                 row!["Mode: ", get_mode_selector(config)]
@@ -35,24 +51,14 @@ impl MenuLauncherSettings {
                 widget::Space::with_height(5),
                 row!["Theme:", get_theme_selector().wrap()].spacing(5),
             ]
-            .spacing(5),
-            column![row![
-                row![widget::text!("UI Scale ({:.2}x)  ", self.temp_scale).size(15)]
-                    .push_maybe(
-                        ((self.temp_scale - config.ui_scale.unwrap_or(1.0)).abs() > 0.01)
-                            .then_some(ui_scale_apply)
-                    )
-                    .align_y(Alignment::Center).width(SETTING_WIDTH),
-                widget::slider(
-                    0.5..=3.0,
-                    self.temp_scale,
-                    |n| LauncherSettingsMessage::UiScale(n).into()
-                ).step(0.1),
-            ]
-            .align_y(Alignment::Center)
-            .spacing(5)],
+            .spacing(5)
+            .into(),
+            SettingsRow {
+                view: ui_scale,
+                action: scale_changed.then(|| LauncherSettingsMessage::UiScaleApply.into()),
+            },
 
-            get_ui_opacity(config),
+            get_ui_opacity(config).into(),
 
             column![
                 // TODO: This requires launcher restart
@@ -74,7 +80,8 @@ impl MenuLauncherSettings {
                 widget::checkbox("Remember last selected instance", config.persistent.clone().unwrap_or_default().selected_remembered)
                     .on_toggle(|n| LauncherSettingsMessage::ToggleInstanceRemembering(n).into()),
             ]
-            .spacing(5),
+            .spacing(5)
+            .into(),
 
             column![
                 row![
@@ -89,8 +96,8 @@ impl MenuLauncherSettings {
                 .spacing(5),
                 widget::text(r#"(Default: 6) Reduces resource usage when launcher is idle.
 Only increase if progress bars stutter or "not responding" dialogs show"#).size(12).style(tsubtitle),
-            ].spacing(5)
-        ])
+            ].spacing(5).into(),
+        ]
     }
 }
 
@@ -178,7 +178,32 @@ impl Launcher {
             };
             return Task::done(msg);
         } else if let State::LauncherSettings(menu) = &mut self.state {
-            if let Key::Named(Named::ArrowUp) = key {
+            let row_actions =
+                menu.selected_tab
+                    .row_actions(&self.config, menu, &self.discord_connection_state);
+            if let (Some(focused), true) = (menu.focused_index, ignored) {
+                // Navigating inside the settings list
+                let last = row_actions.len().saturating_sub(1);
+                match key {
+                    Key::Named(Named::ArrowUp) => {
+                        menu.focused_index = Some(focused.saturating_sub(1));
+                    }
+                    Key::Named(Named::ArrowDown) => {
+                        menu.focused_index = Some((focused + 1).min(last));
+                    }
+                    Key::Named(Named::ArrowLeft) => menu.focused_index = None,
+                    Key::Named(Named::Enter) => {
+                        if let Some(Some(action)) = row_actions.into_iter().nth(focused) {
+                            return Task::done(action);
+                        }
+                    }
+                    _ => {}
+                }
+            } else if let (Key::Named(Named::ArrowRight), true) = (&key, ignored) {
+                if !row_actions.is_empty() {
+                    menu.focused_index = Some(0);
+                }
+            } else if let Key::Named(Named::ArrowUp) = key {
                 return Task::done(LauncherSettingsMessage::Open(menu.selected_tab.prev()).into());
             } else if let Key::Named(Named::ArrowDown) = key {
                 return Task::done(LauncherSettingsMessage::Open(menu.selected_tab.next()).into());
@@ -240,7 +265,7 @@ impl Launcher {
                 self.load_jar_from_path(path, filename);
                 Task::none()
             } else if extension == "qmp" {
-                self.load_qmp_from_path(path)
+                self.confirm_load_qmp_from_path(path)
             } else if extension == "zip" || extension == "mrpack" {
                 self.load_modpack_from_path(path.to_owned())
             } else {
@@ -248,7 +273,7 @@ impl Launcher {
             }
         } else if let State::ManagePresets(_) = &self.state {
             if extension == "qmp" {
-                self.load_qmp_from_path(path)
+                self.confirm_load_qmp_from_path(path)
             } else if extension == "zip" || extension == "mrpack" {
                 self.load_modpack_from_path(path.to_owned())
             } else {
@@ -26,11 +26,9 @@ use std::{
         Arc,
         mpsc::{Receiver, Sender},
     },
+    time::Instant,
 };
 
-pub const SIDEBAR_LIMIT_RIGHT: f32 = 140.0;
-pub const SIDEBAR_LIMIT_LEFT: f32 = 135.0;
-
 mod arrow_keys;
 mod iced_event;
 
@@ -48,7 +46,7 @@ impl Launcher {
         self.load_logs();
         if let State::Launch(menu) = &mut self.state {
             menu.modal = None;
-            menu.reload_notes(instance.clone())
+            menu.reload_instance_info(instance.clone())
         } else {
             Task::none()
         }
@@ -68,9 +66,9 @@ impl Launcher {
             return;
         };
         if let (Some(logs), LaunchTab::Log) = (self.logs.get(instance), menu.tab) {
-            menu.log_state = Some(LogState {
-                content: iced::widget::text_editor::Content::with_text(&logs.log.join("\n")),
-            });
+            let mut state = LogState::new(&logs.log.join("\n"));
+            state.jump_to_match(menu.log_search.as_deref());
+            menu.log_state = Some(state);
         } else {
             menu.log_state = None;
         }
@@ -160,13 +158,22 @@ impl Launcher {
                     details,
                     content_watcher: ContentWatcher::new(&dotmc_dir),
                     index_watcher: FsWatcher::new(ModIndex::get_path(instance)).strerr()?,
+                    local_mods_read_at: QueryType::INDEX_SUPPORTED
+                        .iter()
+                        .map(|n| (*n, Instant::now()))
+                        .collect(),
+                    local_mods_pending: HashSet::new(),
                 },
                 locally_installed_mods,
                 search: None,
                 content_filter: None,
             });
 
-            Ok(Task::batch([update_local_mods_task]))
+            let preload_task = Task::perform(ql_mod_manager::store::preload_metadata(), |()| {
+                Message::Nothing
+            });
+
+            Ok(Task::batch([update_local_mods_task, preload_task]))
         }
         match block_on(inner(self, msg)) {
             Ok(n) => n,
@@ -245,7 +252,42 @@ impl Launcher {
         }
     }
 
-    fn load_qmp_from_path(&mut self, path: &Path) -> Task<Message> {
+    /// Shows what loading a mod preset would change,
+    /// and asks for confirmation before actually loading it.
+    fn confirm_load_qmp_from_path(&mut self, path: &Path) -> Task<Message> {
+        let file = match std::fs::read(path) {
+            Ok(n) => n,
+            Err(err) => {
+                err!("Couldn't drag and drop preset file: {err}");
+                return Task::none();
+            }
+        };
+        let Ok(diff) = block_on(ql_mod_manager::Preset::diff(self.instance(), &file)) else {
+            // Not a regular preset (eg: a modpack), nothing to compare
+            return self.load_qmp_from_path(path);
+        };
+
+        let mut msg2 = format!(
+            "Will install {}, keep {} mods",
+            diff.to_install.len(),
+            diff.already_present.len()
+        );
+        if !diff.to_remove.is_empty() {
+            msg2.push_str(&format!(
+                "\n({} installed mods aren't in this preset, they won't be removed)",
+                diff.to_remove.len()
+            ));
+        }
+        self.state = State::ConfirmAction {
+            msg1: "load this mod preset".to_owned(),
+            msg2,
+            yes: EditPresetsMessage::LoadConfirm(path.to_owned()).into(),
+            no: ManageModsMessage::Open.into(),
+        };
+        Task::none()
+    }
+
+    pub fn load_qmp_from_path(&mut self, path: &Path) -> Task<Message> {
         let file = match std::fs::read(path) {
             Ok(n) => n,
             Err(err) => {
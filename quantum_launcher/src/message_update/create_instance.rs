@@ -4,7 +4,7 @@ use ql_core::{
 };
 
 use crate::{
-    message_handler::{SIDEBAR_LIMIT_LEFT, SIDEBAR_LIMIT_RIGHT},
+    config::clamp_sidebar_ratio,
     state::{
         AutoSaveKind, CreateInstanceMessage, InfoMessage, Launcher, MenuCreateInstance,
         MenuCreateInstanceChoosing, Message, ProgressBar, State,
@@ -67,13 +67,9 @@ impl Launcher {
             }
             CreateInstanceMessage::SidebarResize(ratio) => {
                 let window_width = self.window_state.size.0;
-                let ratio = ratio * window_width;
                 iflet!(self, sidebar_split, sidebar_grid_state; {
                     if let Some(split) = *sidebar_split {
-                        sidebar_grid_state.resize(
-                            split,
-                            ratio.clamp(SIDEBAR_LIMIT_LEFT, window_width - SIDEBAR_LIMIT_RIGHT) / window_width
-                        );
+                        sidebar_grid_state.resize(split, clamp_sidebar_ratio(ratio, window_width));
                     }
                 });
             }
@@ -233,6 +229,7 @@ then go to "Mods->Add File""#,
                 message: Some("Started download".to_owned()),
                 receiver,
                 progress: DownloadProgress::DownloadingJsonManifest,
+                cancel_message: None,
             };
 
             let version = selected_version.clone();
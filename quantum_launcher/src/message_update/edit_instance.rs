@@ -146,6 +146,12 @@ impl Launcher {
                     msg.apply(game_args.get_or_insert_default(), split);
                 });
             }
+            EditInstanceMessage::ClasspathExtras(msg) => {
+                // Paths may contain spaces, so never split them
+                iflet_config!(&mut self.state, classpath_extras, {
+                    msg.apply(classpath_extras.get_or_insert_default(), false);
+                });
+            }
             EditInstanceMessage::PreLaunchPrefix(msg) => {
                 let split = self.should_split_args();
                 iflet_config!(&mut self.state, prefix, |pre_launch_prefix| {
@@ -169,6 +175,7 @@ impl Launcher {
                         .get_name()
                         .clone_into(&mut menu.state_rename.name);
                     menu.state_rename.is_editing = !menu.state_rename.is_editing;
+                    menu.state_rename.error = None;
                 }
             }
             EditInstanceMessage::RenameEdit(n) => {
@@ -178,6 +185,8 @@ impl Launcher {
                 }) = &mut self.state
                 {
                     menu.state_rename.name = n;
+                    let kind = self.selected_instance.as_ref().unwrap().kind;
+                    menu.state_rename.error = menu.validate_instance_name(kind).err();
                 }
             }
             EditInstanceMessage::RenameApply => return self.rename_instance(),
@@ -279,7 +288,7 @@ impl Launcher {
         ) -> Result<(), JsonFileError> {
             let config_path = selected_instance.get_instance_path().join("config.json");
 
-            let config = std::fs::read_to_string(&config_path).path(config_path)?;
+            let config = std::fs::read_to_string(&config_path).path(&config_path)?;
             let config: InstanceConfigJson = serde_json::from_str(&config).json(config)?;
 
             let slider_value = f32::log2(config.ram_in_mb as f32);
@@ -291,6 +300,7 @@ impl Launcher {
             *edit_instance = Some(MenuEditInstance {
                 main_class_mode: config.get_main_class_mode(),
                 config,
+                config_watcher: FsWatcher::watch_file(&config_path).ok(),
                 state_ram: EditInstanceRam {
                     slider_value,
                     slider_text: format_memory_mb(memory_mb),
@@ -304,6 +314,7 @@ impl Launcher {
                     name: selected_instance.name.to_string(),
                     old_name: selected_instance.name.clone(),
                     is_editing: false,
+                    error: None,
                 },
                 arg_split_by_space: true,
             });
@@ -442,12 +453,11 @@ impl Launcher {
             return Ok(Task::none());
         }
 
-        let instances_dir =
-            LAUNCHER_DIR.join(if self.selected_instance.as_ref().unwrap().is_server() {
-                "servers"
-            } else {
-                "instances"
-            });
+        let instances_dir = LAUNCHER_DIR.join(if self.server_selected() {
+            "servers"
+        } else {
+            "instances"
+        });
 
         let old_path = instances_dir.join(&*menu.state_rename.old_name);
         let new_path = instances_dir.join(&sanitized_name);
@@ -501,6 +511,7 @@ impl EditInstanceMessage {
             EditInstanceMessage::JavaArgs(_) |
             EditInstanceMessage::JavaArgsModeChanged(_) |
             EditInstanceMessage::GameArgs(_) |
+            EditInstanceMessage::ClasspathExtras(_) |
             EditInstanceMessage::PreLaunchPrefix(_) |
             EditInstanceMessage::PreLaunchPrefixModeChanged(_) |
             EditInstanceMessage::JavaOverride(_) |
@@ -513,12 +524,19 @@ impl EditInstanceMessage {
     }
 }
 
-fn format_memory_mb(mb_bytes: usize) -> String {
-    const MB_TO_GB: usize = 1024;
-
-    if mb_bytes >= MB_TO_GB {
-        format!("{:.2} GB", mb_bytes as f64 / MB_TO_GB as f64)
+/// Formats an amount of memory (in MB) for the RAM slider,
+/// eg. `512 MB`, `4.0 GB` or `1.5 TB`.
+///
+/// See [`super::format_memory_precise`] for the exact amount.
+fn format_memory_mb(mb: usize) -> String {
+    const MB_IN_GB: usize = 1024;
+    const MB_IN_TB: usize = 1024 * MB_IN_GB;
+
+    if mb >= MB_IN_TB {
+        format!("{:.1} TB", mb as f64 / MB_IN_TB as f64)
+    } else if mb >= MB_IN_GB {
+        format!("{:.1} GB", mb as f64 / MB_IN_GB as f64)
     } else {
-        format!("{mb_bytes} MB")
+        format!("{mb} MB")
     }
 }
@@ -2,7 +2,8 @@ use std::process::ExitStatus;
 
 use iced::{Task, futures::executor::block_on};
 use ql_core::{
-    Instance, InstanceKind, IntoStringError, LaunchedProcess, err, info, pt,
+    Instance, InstanceConfigJson, InstanceKind, IntoStringError, LaunchedProcess, MultiProgress,
+    ProgressId, err, info, pt,
     read_log::{Diagnostic, ReadError},
 };
 use ql_instances::auth::AccountData;
@@ -10,11 +11,10 @@ use tokio::io::AsyncWriteExt;
 
 use crate::{
     config::{AfterLaunchBehavior, sidebar::SidebarSelection},
-    message_handler::{SIDEBAR_LIMIT_LEFT, SIDEBAR_LIMIT_RIGHT},
+    menu_renderer::back_to_launch_screen,
     state::{
         AutoSaveKind, GameProcess, InfoMessage, LaunchMessage, LaunchModal, LaunchTab, Launcher,
-        MainMenuMessage, MenuLaunch, Message, OFFLINE_ACCOUNT_NAME, ProgressBar, SidebarMessage,
-        State,
+        MainMenuMessage, MenuLaunch, Message, OFFLINE_ACCOUNT_NAME, SidebarMessage, State,
     },
 };
 
@@ -28,9 +28,26 @@ impl Launcher {
             LaunchMessage::GameExited(Ok((status, instance, diagnostic))) => {
                 self.set_game_exited(status, &instance, diagnostic)
             }
+            LaunchMessage::GameStopped(instance) => self.set_game_stopped(&instance),
             LaunchMessage::Start => self.launch_start(),
-            LaunchMessage::End(result) => self.finish_launching(result),
+            LaunchMessage::End(result, progress_id) => self.finish_launching(result, progress_id),
             LaunchMessage::Kill => self.kill_selected_instance(),
+            LaunchMessage::AcceptEula(instance) => {
+                self.state = State::GenericMessage("Accepting EULA...".to_owned());
+                self.selected_instance = Some(instance.clone());
+                Task::perform(
+                    async move { ql_servers::accept_eula(&instance.name).await.strerr() },
+                    |n| LaunchMessage::AcceptEulaDone(n).into(),
+                )
+            }
+            LaunchMessage::AcceptEulaDone(Err(err)) => {
+                self.set_error(err);
+                Task::none()
+            }
+            LaunchMessage::AcceptEulaDone(Ok(())) => {
+                let task = self.go_to_main_menu(None);
+                Task::batch([task, self.launch_start()])
+            }
         }
     }
 
@@ -66,11 +83,14 @@ impl Launcher {
             }
             InstanceKind::Server => {
                 let (sender, receiver) = std::sync::mpsc::channel();
-                self.java_recv = Some(ProgressBar::with_recv(receiver));
+                let progress_id = self
+                    .java_recv
+                    .get_or_insert_with(MultiProgress::new)
+                    .add(receiver);
 
                 let server = selected_instance.name.clone();
-                Task::perform(ql_servers::run(server, Some(sender)), |n| {
-                    LaunchMessage::End(n.strerr()).into()
+                Task::perform(ql_servers::run(server, Some(sender)), move |n| {
+                    LaunchMessage::End(n.strerr(), progress_id).into()
                 })
             }
         }
@@ -86,7 +106,10 @@ impl Launcher {
         };
 
         let (sender, receiver) = std::sync::mpsc::channel();
-        self.java_recv = Some(ProgressBar::with_recv(receiver));
+        let progress_id = self
+            .java_recv
+            .get_or_insert_with(MultiProgress::new)
+            .add(receiver);
 
         let global_settings = self.config.global_settings.clone();
         let extra_java_args = self.config.extra_java_args.clone().unwrap_or_default();
@@ -101,7 +124,7 @@ impl Launcher {
                 global_settings,
                 extra_java_args,
             ),
-            |n| LaunchMessage::End(n.strerr()).into(),
+            move |n| LaunchMessage::End(n.strerr(), progress_id).into(),
         )
     }
 
@@ -118,6 +141,9 @@ impl Launcher {
         };
         info!("Game exited ({status})");
 
+        let needs_eula =
+            instance.is_server() && matches!(diagnostic, Some(Diagnostic::EulaNotAccepted));
+
         let log_state = if let State::Launch(MenuLaunch {
             message, log_state, ..
         }) = &mut self.state
@@ -140,20 +166,61 @@ impl Launcher {
         };
 
         if let Some(process) = self.processes.remove(instance) {
-            Self::read_game_logs(
+            let truncated = Self::read_game_logs(
                 &process,
                 instance,
                 &mut self.logs,
                 log_state,
                 self.selected_instance.as_ref(),
             );
+            if truncated && self.selected_instance.as_ref() == Some(instance) {
+                if let (State::Launch(menu), Some(log)) = (&mut self.state, self.logs.get(instance))
+                {
+                    menu.refresh_log_search(log);
+                }
+            }
+        }
+
+        if needs_eula {
+            self.state = State::ConfirmAction {
+                msg1: "accept the Minecraft EULA".to_owned(),
+                msg2: "The server won't start until you do.\nRead it here: https://aka.ms/MinecraftEULA".to_owned(),
+                yes: LaunchMessage::AcceptEula(instance.clone()).into(),
+                no: back_to_launch_screen(None),
+            };
         }
 
         self.rpc_game_update(instance.clone(), true)
     }
 
-    fn finish_launching(&mut self, result: Result<LaunchedProcess, String>) -> Task<Message> {
-        self.java_recv = None;
+    fn set_game_stopped(&mut self, instance: &Instance) -> Task<Message> {
+        info!("Game was stopped");
+        let task = self.set_game_exited(ExitStatus::default(), instance, None);
+        if let State::Launch(MenuLaunch { message, .. }) = &mut self.state {
+            *message = Some(InfoMessage::success(format!(
+                "{} was stopped",
+                if instance.is_server() {
+                    "Server"
+                } else {
+                    "Instance"
+                }
+            )));
+        }
+        task
+    }
+
+    fn finish_launching(
+        &mut self,
+        result: Result<LaunchedProcess, String>,
+        progress_id: ProgressId,
+    ) -> Task<Message> {
+        // Other instances may still be installing Java
+        if let Some(progress) = &mut self.java_recv {
+            progress.remove(progress_id);
+            if progress.is_empty() {
+                self.java_recv = None;
+            }
+        }
         self.is_launching_game = false;
         match result {
             Ok(child) => {
@@ -182,13 +249,19 @@ impl Launcher {
                 }
 
                 let version_presence_task = self.rpc_game_update(selected_instance.clone(), false);
-
                 let log_task = Task::perform(
                     async move {
-                        let result = child.read_logs(censors, Some(sender)).await;
+                        let hang_timeout = InstanceConfigJson::read(&selected_instance)
+                            .await
+                            .ok()
+                            .and_then(|n| n.get_hang_timeout());
+                        let result = child.read_logs(censors, Some(sender), hang_timeout).await;
+                        if let Some(Err(ReadError::ProcessKilled)) = &result {
+                            return LaunchMessage::GameStopped(selected_instance);
+                        }
                         let default_output = Ok((ExitStatus::default(), selected_instance, None));
 
-                        match result {
+                        LaunchMessage::GameExited(match result {
                             Some(Err(ReadError::Io(io)))
                                 if io.kind() == std::io::ErrorKind::InvalidData =>
                             {
@@ -198,11 +271,29 @@ impl Launcher {
                             }
                             Some(result) => result.strerr(),
                             None => default_output,
-                        }
+                        })
                     },
-                    |n| LaunchMessage::GameExited(n).into(),
+                    Message::from,
                 );
 
+                // Leftover Forge installer files, cleaned up in
+                // the background so they don't slow down launching
+                let cleanup_task = if selected_instance.is_server() {
+                    Task::none()
+                } else {
+                    Task::perform(
+                        ql_instances::cleanup_junk_files(selected_instance.clone()),
+                        |result| {
+                            match result {
+                                Ok(0) => {}
+                                Ok(n) => info!("Cleaned up {n} junk files"),
+                                Err(err) => err!("Couldn't clean up junk files: {err}"),
+                            }
+                            Message::Nothing
+                        },
+                    )
+                };
+
                 match self.config.c_after_launch_behavior() {
                     AfterLaunchBehavior::DoNothing => {}
                     AfterLaunchBehavior::CloseLauncher => {
@@ -212,11 +303,16 @@ impl Launcher {
                     AfterLaunchBehavior::MinimizeLauncher => {
                         let minimize_task = iced::window::get_latest()
                             .and_then(|id| iced::window::minimize(id, true));
-                        return Task::batch([log_task, minimize_task, version_presence_task]);
+                        return Task::batch([
+                            log_task,
+                            minimize_task,
+                            version_presence_task,
+                            cleanup_task,
+                        ]);
                     }
                 }
 
-                return Task::batch([log_task, version_presence_task]);
+                return Task::batch([log_task, version_presence_task, cleanup_task]);
             }
             Err(err) => self.set_error(err),
         }
@@ -230,6 +326,7 @@ impl Launcher {
         match instance.kind {
             InstanceKind::Client => {
                 if let Some(process) = self.processes.remove(instance) {
+                    process.child.mark_killed_by_user();
                     let mut child = block_on(process.child.child.lock());
                     _ = child.start_kill();
                 }
@@ -243,6 +340,7 @@ impl Launcher {
                 {
                     *has_issued_stop_command = true;
                     if child.is_classic_server {
+                        child.mark_killed_by_user();
                         _ = block_on(child.child.lock()).start_kill();
                     } else {
                         let future = stdin.write_all("stop\n".as_bytes());
@@ -307,6 +405,15 @@ impl Launcher {
                     menu.message = msg;
                 }
             }
+            MainMenuMessage::PropertiesLoaded(instance, properties) => {
+                // Ignore results for an instance that's no longer selected
+                if let (State::Launch(menu), true) = (
+                    &mut self.state,
+                    self.selected_instance.as_ref() == Some(&instance),
+                ) {
+                    menu.properties = Some(properties);
+                }
+            }
         }
         Task::none()
     }
@@ -321,12 +428,7 @@ impl Launcher {
         match message {
             SidebarMessage::Resize(ratio) => {
                 if let State::Launch(menu) = &mut self.state {
-                    let window_width = self.window_state.size.0;
-                    let ratio = ratio * window_width;
-                    menu.resize_sidebar(
-                        ratio.clamp(SIDEBAR_LIMIT_LEFT, window_width - SIDEBAR_LIMIT_RIGHT)
-                            / window_width,
-                    );
+                    menu.resize_sidebar(ratio, self.window_state.size.0);
                 }
             }
             SidebarMessage::Scroll(scroll) => {
@@ -271,6 +271,7 @@ impl Launcher {
         self.state = State::LauncherSettings(state::MenuLauncherSettings {
             temp_scale: self.config.ui_scale.unwrap_or(1.0),
             selected_tab,
+            focused_index: None,
             arg_split_by_space: true,
             outmsg: None,
             outmsg_at: state::SettingsOutmsg::Assets,
@@ -454,6 +455,19 @@ impl Launcher {
                     }
                 }
             }
+            GameLogMessage::Search(query) => {
+                let results = match (&query, self.logs.get(self.instance())) {
+                    (Some(query), Some(log)) => log.search(query),
+                    _ => Vec::new(),
+                };
+                if let State::Launch(menu) = &mut self.state {
+                    if let Some(logs) = &mut menu.log_state {
+                        logs.jump_to_match(query.as_deref());
+                    }
+                    menu.log_search = query;
+                    menu.search_log_results = results;
+                }
+            }
             GameLogMessage::Copy => {
                 let instance = self.instance();
                 if let Some(log) = self.logs.get(instance) {
@@ -554,3 +568,9 @@ pub fn format_memory_bytes(bytes: u64) -> String {
         format!("{bytes} bytes")
     }
 }
+
+/// Formats an amount of memory as an exact number of MB,
+/// without rounding to GB/TB like the RAM slider does.
+pub fn format_memory_precise(mb: usize) -> String {
+    format!("{mb} MB")
+}
@@ -1,9 +1,7 @@
 use std::{collections::HashMap, time::Instant};
 
 use iced::{Task, futures::executor::block_on, widget::scrollable::AbsoluteOffset};
-use ql_core::{
-    InstanceConfigJson, InstanceKind, IntoStringError, JsonFileError, err, json::VersionDetails,
-};
+use ql_core::{InstanceConfigJson, IntoStringError, JsonFileError, err, json::VersionDetails};
 use ql_mod_manager::store::{
     self, ModId, ModIndex, Query, QueryType, StoreBackendType, get_description,
 };
@@ -15,10 +13,7 @@ use crate::state::{
 
 impl Launcher {
     pub fn update_install_mods(&mut self, message: InstallModsMessage) -> Task<Message> {
-        let is_server = matches!(
-            self.selected_instance.as_ref().map(|n| n.kind),
-            Some(InstanceKind::Server)
-        );
+        let is_server = self.server_selected();
 
         match message {
             InstallModsMessage::LoadedDescription(Err(err))
@@ -65,6 +60,7 @@ impl Launcher {
                     }
 
                     menu.scroll_offset = absolute_offset;
+                    menu.preload_images(&mut self.images);
                     if (scroll_px > total_height)
                         && !menu.is_loading_continuation
                         && !menu.has_continuation_ended
@@ -80,10 +76,20 @@ impl Launcher {
                     }
                 }
             }
-            InstallModsMessage::Open => match block_on(self.open_mods_store()) {
-                Ok(command) => return command,
-                Err(err) => self.set_error(err),
-            },
+            InstallModsMessage::Open => {
+                if let Some(menu) = self.mods_store_behind_prompt.take() {
+                    let scroll_offset = menu.scroll_offset;
+                    self.state = State::ModsDownload(*menu);
+                    return iced::widget::scrollable::scroll_to(
+                        iced::widget::scrollable::Id::new("MenuModsDownload:main:mods_list"),
+                        scroll_offset,
+                    );
+                }
+                match block_on(self.open_mods_store()) {
+                    Ok(command) => return command,
+                    Err(err) => self.set_error(err),
+                }
+            }
             InstallModsMessage::TickDesc(update_msg) => {
                 if let State::ModsDownload(MenuModsDownload {
                     description: Some(description),
@@ -161,7 +167,7 @@ impl Launcher {
                 }
             }
             InstallModsMessage::Download(index) => {
-                return self.mod_download(index);
+                return self.mod_download(index, false);
             }
             InstallModsMessage::DownloadComplete(Ok((id, not_allowed))) => {
                 let task = if let State::ModsDownload(menu) = &mut self.state {
@@ -195,6 +201,8 @@ impl Launcher {
                 if let State::ModsDownload(menu) = &mut self.state {
                     menu.backend = backend;
                     menu.results = None;
+                    // Ignore any pending searches from the old backend
+                    menu.latest_load = Instant::now();
                     menu.scroll_offset = AbsoluteOffset::default();
                     menu.categories.reset();
 
@@ -237,6 +245,12 @@ impl Launcher {
                 }
             }
 
+            InstallModsMessage::DownloadAnyway(index) => {
+                if let Some(menu) = self.mods_store_behind_prompt.take() {
+                    self.state = State::ModsDownload(*menu);
+                    return self.mod_download(index, true);
+                }
+            }
             InstallModsMessage::InstallModpack(id) => {
                 let (sender, receiver) = std::sync::mpsc::channel();
                 self.state = State::ImportModpack(ProgressBar::with_recv(receiver));
@@ -326,7 +340,7 @@ impl Launcher {
         Ok(command)
     }
 
-    fn mod_download(&mut self, index: usize) -> Task<Message> {
+    fn mod_download(&mut self, index: usize, ignore_conflicts: bool) -> Task<Message> {
         let selected_instance = self.instance().clone();
         let State::ModsDownload(menu) = &mut self.state else {
             return Task::none();
@@ -340,15 +354,43 @@ impl Launcher {
             return Task::none();
         };
 
-        menu.mods_download_in_progress.insert(
-            ModId::from_pair(&hit.id, results.backend),
-            (hit.title.clone(), ModOperation::Downloading),
-        );
-
         let project_id = hit.id.clone();
         let backend = menu.backend;
         let id = ModId::from_pair(&project_id, backend);
 
+        let conflicts: Vec<String> = if ignore_conflicts {
+            Vec::new()
+        } else {
+            menu.mod_index
+                .find_conflicts(std::slice::from_ref(&id))
+                .into_iter()
+                .map(|(_, other)| {
+                    menu.mod_index.mods.get(&other).map_or_else(
+                        || other.get_internal_id().to_string(),
+                        |n| n.name.to_string(),
+                    )
+                })
+                .collect()
+        };
+        if !conflicts.is_empty() {
+            let prompt = State::ConfirmAction {
+                msg1: format!("install {}", hit.title),
+                msg2: format!(
+                    "It's known to conflict with: {}\nUsing them together may crash the game or break things",
+                    conflicts.join(", ")
+                ),
+                yes: InstallModsMessage::DownloadAnyway(index).into(),
+                no: InstallModsMessage::Open.into(),
+            };
+            if let State::ModsDownload(menu) = std::mem::replace(&mut self.state, prompt) {
+                self.mods_store_behind_prompt = Some(Box::new(menu));
+            }
+            return Task::none();
+        }
+
+        menu.mods_download_in_progress
+            .insert(id.clone(), (hit.title.clone(), ModOperation::Downloading));
+
         if let QueryType::ModPacks = menu.query_type {
             self.state = State::ConfirmAction {
                 msg1: format!("install the modpack: {}", hit.title),
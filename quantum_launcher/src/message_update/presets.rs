@@ -74,6 +74,11 @@ impl Launcher {
                     Err(err) => self.set_error(err),
                 }
             }
+            EditPresetsMessage::LoadConfirm(path) => {
+                // `load_qmp_from_path` expects to be in the mods menu
+                let task = self.go_to_edit_mods_menu(None);
+                return Task::batch([task, self.load_qmp_from_path(&path)]);
+            }
             EditPresetsMessage::LoadComplete(result) => {
                 match result.map(|not_allowed| {
                     if not_allowed.is_empty() {
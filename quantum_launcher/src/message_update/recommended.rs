@@ -81,19 +81,30 @@ impl Launcher {
                         .map(|n| ModId::from_pair(n.1.id, n.1.backend))
                         .collect();
 
-                    self.state = State::RecommendedMods(MenuRecommendedMods::Loading {
-                        progress: ProgressBar::with_recv(receiver),
-                        config: config.clone(),
-                    });
-
                     let instance = self.selected_instance.clone().unwrap();
 
-                    return Task::perform(
+                    let (task, handle) = Task::perform(
                         ql_mod_manager::store::download_mods_bulk(ids, instance, Some(sender)),
                         |n| RecommendedModMessage::DownloadEnd(n.strerr()).into(),
-                    );
+                    )
+                    .abortable();
+
+                    self.state = State::RecommendedMods(MenuRecommendedMods::Loading {
+                        progress: ProgressBar::with_recv(receiver)
+                            .with_cancel(RecommendedModMessage::DownloadCancel.into()),
+                        config: config.clone(),
+                        _download_handle: Some(handle.abort_on_drop()),
+                    });
+
+                    return task;
                 }
             }
+            RecommendedModMessage::DownloadCancel => {
+                // Leaving this menu drops the handle, aborting the download
+                return self.go_to_edit_mods_menu(Some(InfoMessage::error(
+                    "Cancelled downloading recommended mods",
+                )));
+            }
             RecommendedModMessage::DownloadEnd(result) => match result {
                 Ok(not_allowed) => {
                     if not_allowed.is_empty() {
@@ -129,6 +140,7 @@ impl Launcher {
         self.state = State::RecommendedMods(MenuRecommendedMods::Loading {
             progress,
             config: config.clone(),
+            _download_handle: None,
         });
         let loader = config.mod_type;
         if loader.is_vanilla() {
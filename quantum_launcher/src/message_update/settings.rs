@@ -1,13 +1,19 @@
 use crate::{
     config::UiWindowDecorations,
     message_update::MSG_RESIZE,
-    state::{AutoSaveKind, Launcher, LauncherSettingsMessage, LauncherSettingsTab, Message, State},
+    state::{
+        AutoSaveKind, Launcher, LauncherSettingsMessage, LauncherSettingsTab, Message,
+        SettingsOutmsg, State, get_entries,
+    },
 };
 use iced::Task;
-use ql_core::{IntoStringError, err};
+use ql_core::{InstanceKind, IntoStringError, err};
 
 impl Launcher {
     pub fn update_launcher_settings(&mut self, msg: LauncherSettingsMessage) -> Task<Message> {
+        // Almost every setting changes the config,
+        // so it's saved on the next tick
+        self.autosave.remove(&AutoSaveKind::LauncherConfig);
         match msg {
             LauncherSettingsMessage::ThemePicked(theme) => {
                 self.config.ui_mode = Some(theme);
@@ -53,6 +59,28 @@ impl Launcher {
                     LauncherSettingsMessage::Open(LauncherSettingsTab::Launcher).into()
                 });
             }
+            LauncherSettingsMessage::CheckJavaVersions => {
+                return Task::perform(ql_instances::get_available_java_versions(), |r| {
+                    LauncherSettingsMessage::CheckJavaVersionsDone(r.strerr()).into()
+                });
+            }
+            LauncherSettingsMessage::CheckJavaVersionsDone(r) => match r {
+                Ok(versions) => {
+                    if let State::LauncherSettings(menu) = &mut self.state {
+                        menu.outmsg = Some(if versions.is_empty() {
+                            "None (will use alternate sources)".to_owned()
+                        } else {
+                            versions
+                                .iter()
+                                .map(|n| (*n as usize).to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        });
+                        menu.outmsg_at = crate::state::SettingsOutmsg::JavaVersions;
+                    }
+                }
+                Err(err) => self.set_error(err),
+            },
             LauncherSettingsMessage::CleanAssets => {
                 return Task::perform(ql_core::clean::assets_dir(), |r| {
                     LauncherSettingsMessage::CleanAssetsFinished(r.strerr()).into()
@@ -67,6 +95,33 @@ impl Launcher {
                 }
                 Err(err) => self.set_error(err),
             },
+            LauncherSettingsMessage::RemoveOrphanedInstances => {
+                return Task::perform(ql_core::clean::find_orphaned_instances(), |n| {
+                    LauncherSettingsMessage::RemoveOrphanedInstancesFound(n).into()
+                });
+            }
+            LauncherSettingsMessage::RemoveOrphanedInstancesFound(names) => {
+                if names.is_empty() {
+                    self.set_orphaned_outmsg("No broken instances found".to_owned());
+                } else {
+                    self.state = State::ConfirmAction {
+                        msg1: format!("delete these broken instances: {}", names.join(", ")),
+                        msg2: "They are missing config.json, usually because creating them was interrupted.".to_owned(),
+                        yes: LauncherSettingsMessage::RemoveOrphanedInstancesConfirm(names).into(),
+                        no: LauncherSettingsMessage::Open(LauncherSettingsTab::Launcher).into(),
+                    };
+                }
+            }
+            LauncherSettingsMessage::RemoveOrphanedInstancesConfirm(names) => {
+                return Task::perform(ql_core::clean::remove_orphaned_instances(names), |n| {
+                    LauncherSettingsMessage::RemoveOrphanedInstancesDone(n).into()
+                });
+            }
+            LauncherSettingsMessage::RemoveOrphanedInstancesDone(removed) => {
+                self.go_to_launcher_settings(LauncherSettingsTab::Launcher);
+                self.set_orphaned_outmsg(format!("Removed {} instance(s)", removed.len()));
+                return Task::perform(get_entries(InstanceKind::Client), Message::CoreListLoaded);
+            }
             LauncherSettingsMessage::ClearDownloadCache => {
                 return Task::perform(ql_core::clean::clear_cache_dir(), |r| {
                     LauncherSettingsMessage::ClearDownloadCacheDone(r.strerr()).into()
@@ -103,7 +158,6 @@ impl Launcher {
             }
             LauncherSettingsMessage::AfterLaunchBehaviorChanged(behavior) => {
                 self.config.ui.get_or_insert_default().after_game_opens = behavior;
-                self.autosave.remove(&AutoSaveKind::LauncherConfig);
             }
             LauncherSettingsMessage::DefaultMinecraftWidthChanged(input) => {
                 self.config.c_global().window_width = input.trim().parse::<u32>().ok();
@@ -153,4 +207,10 @@ impl Launcher {
         }
         Task::none()
     }
+    fn set_orphaned_outmsg(&mut self, msg: String) {
+        if let State::LauncherSettings(menu) = &mut self.state {
+            menu.outmsg = Some(msg);
+            menu.outmsg_at = SettingsOutmsg::OrphanedInstances;
+        }
+    }
 }
@@ -3,16 +3,16 @@ use std::{
     collections::{HashMap, HashSet},
     path::{Path, PathBuf},
     sync::Arc,
-    time::Instant,
+    time::{Duration, Instant},
 };
 
 use crate::{
     config::{
-        SIDEBAR_WIDTH,
+        SIDEBAR_WIDTH, clamp_sidebar_ratio,
         sidebar::{FolderId, SDragLocation, SidebarSelection},
     },
     message_handler::get_locally_installed_mods,
-    state::{FsWatcher, NotesMessage},
+    state::{FsWatcher, MainMenuMessage, NotesMessage},
 };
 use ezshortcut::Shortcut;
 use frostmark::MarkState;
@@ -96,8 +96,119 @@ impl InstanceNotes {
     }
 }
 
+/// Quick-view info about the selected instance,
+/// shown on the main screen. Loaded in the background.
+#[derive(Debug, Clone, Default)]
+pub struct InstanceProperties {
+    pub config: Option<InstanceConfigJson>,
+    /// Minecraft version, from `details.json`
+    pub version: Option<String>,
+    /// Total size of the instance folder, in bytes
+    pub disk_usage: Option<u64>,
+}
+
+impl InstanceProperties {
+    pub async fn load(instance: Instance) -> Self {
+        let config = InstanceConfigJson::read(&instance).await.ok();
+        let version = VersionDetails::load(&instance)
+            .await
+            .ok()
+            .map(|details| details.get_id().to_owned());
+        let disk_usage = ql_core::clean::size_of_dir(&instance.get_instance_path())
+            .await
+            .ok();
+        Self {
+            config,
+            version,
+            disk_usage,
+        }
+    }
+}
+
 pub struct LogState {
     pub content: widget::text_editor::Content,
+    /// The selected log search match, as
+    /// `(line, column, length)` in characters.
+    ///
+    /// While this is set, new log lines don't
+    /// scroll the log to the bottom.
+    pub search_match: Option<(usize, usize, usize)>,
+}
+
+impl LogState {
+    pub fn new(text: &str) -> Self {
+        Self {
+            content: widget::text_editor::Content::with_text(text),
+            search_match: None,
+        }
+    }
+
+    /// Selects the first match of `query` (case-insensitive),
+    /// scrolling the log to it.
+    ///
+    /// Passing `None` clears the search and goes back
+    /// to following the end of the log.
+    pub fn jump_to_match(&mut self, query: Option<&str>) {
+        let query = query.filter(|n| !n.is_empty()).map(str::to_lowercase);
+        self.search_match = query.and_then(|query| {
+            self.content
+                .text()
+                .lines()
+                .enumerate()
+                .find_map(|(i, line)| {
+                    let line = line.to_lowercase();
+                    let start = line.find(&query)?;
+                    Some((i, line[..start].chars().count(), query.chars().count()))
+                })
+        });
+
+        if self.search_match.is_some() {
+            self.select_match();
+        } else {
+            self.content.perform(widget::text_editor::Action::Move(
+                widget::text_editor::Motion::DocumentEnd,
+            ));
+        }
+    }
+
+    /// Moves the cursor back to `search_match` and
+    /// selects it (for when the content changes).
+    pub fn select_match(&mut self) {
+        use widget::text_editor::{Action, Motion};
+
+        let Some((line, column, len)) = self.search_match else {
+            return;
+        };
+        let content = &mut self.content;
+
+        // Wrapped lines take several `Up`/`Down` steps,
+        // so check the actual line instead of counting.
+        // Start from whichever end is closer.
+        let (start, step) = if line < content.line_count() / 2 {
+            (Motion::DocumentStart, Motion::Down)
+        } else {
+            (Motion::DocumentEnd, Motion::Up)
+        };
+        content.perform(Action::Move(start));
+        while content.cursor_position().0 != line {
+            let before = content.cursor_position();
+            content.perform(Action::Move(step));
+            if content.cursor_position() == before {
+                return;
+            }
+        }
+
+        content.perform(Action::Move(Motion::Home));
+        while content.cursor_position().1 > 0 {
+            content.perform(Action::Move(Motion::Left));
+        }
+        for _ in 0..column {
+            content.perform(Action::Move(Motion::Right));
+        }
+        for _ in 0..len {
+            content.perform(Action::Select(Motion::Right));
+        }
+    }
 }
 
 /// The home screen of the launcher.
@@ -107,6 +218,7 @@ pub struct MenuLaunch {
     pub tab: LaunchTab,
     pub edit_instance: Option<MenuEditInstance>,
     pub notes: Option<InstanceNotes>,
+    pub properties: Option<InstanceProperties>,
     pub log_state: Option<LogState>,
     pub modal: Option<LaunchModal>,
 
@@ -115,6 +227,11 @@ pub struct MenuLaunch {
     sidebar_split: Option<widget::pane_grid::Split>,
 
     pub is_uploading_mclogs: bool,
+    /// The text in the log search box (if any)
+    pub log_search: Option<String>,
+    /// Indices of log lines matching `log_search`,
+    /// see [`crate::state::InstanceLog::search`]
+    pub search_log_results: Vec<usize>,
 }
 
 impl Default for MenuLaunch {
@@ -160,15 +277,21 @@ impl MenuLaunch {
             sidebar_grid_state,
             log_state: None,
             is_uploading_mclogs: false,
+            log_search: None,
+            search_log_results: Vec::new(),
             sidebar_split,
             notes: None,
+            properties: None,
             modal: None,
         }
     }
 
-    pub fn resize_sidebar(&mut self, width: f32) {
+    /// Resizes the sidebar to `ratio` of the window width,
+    /// clamped to the sidebar limits (see [`clamp_sidebar_ratio`]).
+    pub fn resize_sidebar(&mut self, ratio: f32, window_width: f32) {
         if let Some(split) = self.sidebar_split {
-            self.sidebar_grid_state.resize(split, width);
+            self.sidebar_grid_state
+                .resize(split, clamp_sidebar_ratio(ratio, window_width));
         }
     }
 
@@ -179,6 +302,34 @@ impl MenuLaunch {
         })
     }
 
+    pub fn reload_properties(&mut self, instance: Instance) -> Task<Message> {
+        self.properties = None;
+        Task::perform(InstanceProperties::load(instance.clone()), move |n| {
+            MainMenuMessage::PropertiesLoaded(instance, n).into()
+        })
+    }
+
+    /// Re-runs the log search, as line indices in
+    /// `search_log_results` shift when old lines are removed
+    /// (see [`crate::state::InstanceLog::truncate_to`]).
+    pub fn refresh_log_search(&mut self, log: &crate::state::InstanceLog) {
+        if let Some(query) = &self.log_search {
+            self.search_log_results = log.search(query);
+            if let Some(state) = &mut self.log_state {
+                state.jump_to_match(Some(query));
+            }
+        }
+    }
+
+    /// Reloads everything shown about the selected instance
+    /// (notes and properties).
+    pub fn reload_instance_info(&mut self, instance: Instance) -> Task<Message> {
+        Task::batch([
+            self.reload_notes(instance.clone()),
+            self.reload_properties(instance),
+        ])
+    }
+
     pub fn get_modal_drag(&self) -> Option<(&SidebarSelection, Option<&SDragLocation>)> {
         if let Some(LaunchModal::SDragging {
             being_dragged,
@@ -194,6 +345,9 @@ impl MenuLaunch {
 /// The screen where you can edit an instance/server.
 pub struct MenuEditInstance {
     pub config: InstanceConfigJson,
+    /// Detects `config.json` being edited outside the launcher
+    /// (eg: in a text editor), to reload it.
+    pub config_watcher: Option<FsWatcher>,
 
     pub state_rename: EditInstanceRename,
     pub state_ram: EditInstanceRam,
@@ -206,6 +360,32 @@ pub struct EditInstanceRename {
     pub is_editing: bool,
     pub name: String,
     pub old_name: Arc<str>,
+    /// Shown below the text box if `name` isn't valid,
+    /// see [`MenuEditInstance::validate_instance_name`]
+    pub error: Option<&'static str>,
+}
+
+impl MenuEditInstance {
+    const MAX_NAME_LEN: usize = 64;
+
+    /// Checks the name being typed in the rename box,
+    /// so problems can be shown before clicking "Rename".
+    pub fn validate_instance_name(&self, kind: InstanceKind) -> Result<(), &'static str> {
+        let name = self.state_rename.name.trim();
+        if name.is_empty() {
+            return Err("Name can't be empty");
+        }
+        if name.contains(['/', '\\', ':']) {
+            return Err("Name can't contain /, \\ or :");
+        }
+        if name.chars().count() > Self::MAX_NAME_LEN {
+            return Err("Name is too long (max 64 characters)");
+        }
+        if name != &*self.state_rename.old_name && Instance::new(name, kind).exists() {
+            return Err("An instance with this name already exists");
+        }
+        Ok(())
+    }
 }
 
 pub struct EditInstanceRam {
@@ -394,6 +574,13 @@ pub struct EditModsFileData {
 
     pub content_watcher: ContentWatcher,
     pub index_watcher: FsWatcher,
+
+    /// When each content folder was last read from disk.
+    /// See [`MenuEditMods::refresh_locally_installed_mods`].
+    pub local_mods_read_at: HashMap<QueryType, Instant>,
+    /// Content folders that changed, but were read too recently
+    /// to be read again yet.
+    pub local_mods_pending: HashSet<QueryType>,
 }
 
 pub struct ContentWatcher {
@@ -512,7 +699,41 @@ pub enum MenuEditModsModal {
     AddFile,
 }
 
+/// How long a read of a content folder is considered fresh.
+///
+/// Copying in a bunch of files fires many watcher events in a row,
+/// so this avoids reading the folder again for every single one.
+const LOCAL_MODS_CACHE_TTL: Duration = Duration::from_secs(2);
+
 impl MenuEditMods {
+    /// Re-reads the content folders that changed on disk,
+    /// skipping (for now) any that were read in the last
+    /// [`LOCAL_MODS_CACHE_TTL`].
+    ///
+    /// Skipped folders stay pending, and get read
+    /// on a later call once their cache expires.
+    pub fn refresh_locally_installed_mods(&mut self, instance: &Instance) -> Task<Message> {
+        let now = Instant::now();
+        let data = &mut self.file_data;
+
+        let due: Vec<QueryType> = data
+            .local_mods_pending
+            .iter()
+            .copied()
+            .filter(|project_type| {
+                data.local_mods_read_at
+                    .get(project_type)
+                    .is_none_or(|at| now.duration_since(*at) >= LOCAL_MODS_CACHE_TTL)
+            })
+            .collect();
+
+        Task::batch(due.into_iter().map(|project_type| {
+            data.local_mods_pending.remove(&project_type);
+            data.local_mods_read_at.insert(project_type, now);
+            Self::update_locally_installed_mods(&data.mod_index, instance, project_type)
+        }))
+    }
+
     pub fn update_locally_installed_mods(
         idx: &ModIndex,
         selected_instance: &Instance,
@@ -728,6 +949,28 @@ impl MenuModsDownload {
             images.queue(&img, false);
         }
     }
+
+    /// Queues the icons of the next few mods (from the current
+    /// scroll position onwards), so they're already loaded
+    /// by the time the user scrolls to them.
+    pub fn preload_images(&self, images: &mut ImageState) {
+        // Height of each entry in the list (including spacing),
+        // see `MOD_HEIGHT` in `menu_renderer/mods/mods_store.rs`
+        const ENTRY_HEIGHT: f32 = 55.0 + 5.0;
+        const PRELOAD_COUNT: usize = 10;
+
+        let Some(results) = &self.results else {
+            return;
+        };
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let first = (self.scroll_offset.y / ENTRY_HEIGHT).max(0.0) as usize;
+
+        for hit in results.mods.iter().skip(first).take(PRELOAD_COUNT) {
+            if let Some(url) = &hit.icon_url {
+                images.queue(url, true);
+            }
+        }
+    }
 }
 
 pub struct ModCategoryState {
@@ -767,6 +1010,11 @@ impl ModCategoryState {
 
 pub struct MenuLauncherSettings {
     pub selected_tab: LauncherSettingsTab,
+    /// The settings row highlighted by keyboard navigation.
+    ///
+    /// `None` means the tab sidebar has focus,
+    /// so arrow keys switch between tabs instead.
+    pub focused_index: Option<usize>,
 
     pub temp_scale: f64,
     pub arg_split_by_space: bool,
@@ -778,6 +1026,8 @@ pub struct MenuLauncherSettings {
 pub enum SettingsOutmsg {
     Assets,
     Cache,
+    OrphanedInstances,
+    JavaVersions,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -845,6 +1095,9 @@ pub enum MenuRecommendedMods {
     Loading {
         progress: ProgressBar<GenericProgress>,
         config: InstanceConfigJson,
+        /// Aborts the mod download when dropped,
+        /// ie. when cancelled.
+        _download_handle: Option<iced::task::Handle>,
     },
     Loaded {
         mods: Vec<(bool, RecommendedMod)>,
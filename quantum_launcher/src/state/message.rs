@@ -6,13 +6,13 @@ use crate::{
         sidebar::{FolderId, SDragLocation, SidebarSelection},
     },
     message_handler::ForgeKind,
-    state::{InfoMessage, LaunchModal, MenuEditModsModal, SidebarScroll},
+    state::{InfoMessage, InstanceProperties, LaunchModal, MenuEditModsModal, SidebarScroll},
     stylesheet::styles::{LauncherThemeColor, LauncherThemeLightness},
 };
 use filthy_rich::PresenceClient;
 use iced::widget::{self, scrollable::AbsoluteOffset};
 use ql_core::{
-    Instance, InstanceKind, LaunchedProcess, ListEntry, Loader,
+    Instance, InstanceKind, JavaVersion, LaunchedProcess, ListEntry, Loader, ProgressId,
     file_utils::DirItem,
     jarmod::JarMods,
     json::instance_config::{MainClassMode, PreLaunchPrefixMode},
@@ -92,6 +92,7 @@ pub enum EditInstanceMessage {
     JavaArgs(ListMessage),
     JavaArgsModeChanged(bool),
     GameArgs(ListMessage),
+    ClasspathExtras(ListMessage),
     ToggleSplitArg(bool),
 
     PreLaunchPrefix(ListMessage),
@@ -187,6 +188,8 @@ pub enum InstallModsMessage {
     SearchInput(String),
     SearchResult(Res<SearchResult>),
     Download(usize),
+    /// Download even though it conflicts with installed mods
+    DownloadAnyway(usize),
     DownloadComplete(Res<(ModId, HashSet<CurseforgeNotAllowed>)>),
     InstallModpack(ModId),
     Uninstall(usize),
@@ -218,6 +221,7 @@ pub enum EditPresetsMessage {
     SelectAll,
     BuildYourOwn,
     BuildYourOwnEnd(Res<Vec<u8>>),
+    LoadConfirm(PathBuf),
     LoadComplete(Res<HashSet<CurseforgeNotAllowed>>),
 }
 
@@ -228,6 +232,7 @@ pub enum RecommendedModMessage {
     Toggle(usize, bool),
     ToggleFilter(ql_mod_manager::store::recommended::Category, bool),
     Download,
+    DownloadCancel,
     DownloadEnd(Res<HashSet<CurseforgeNotAllowed>>),
 }
 
@@ -291,12 +296,19 @@ pub enum LauncherSettingsMessage {
 
     ClearJavaInstalls,
     ClearJavaInstallsConfirm,
+    CheckJavaVersions,
+    CheckJavaVersionsDone(Res<Vec<JavaVersion>>),
     ClearDownloadCache,
     ClearDownloadCacheDone(Res<u64>),
 
     CleanAssets, // Non-destructive
     CleanAssetsFinished(Res<u64>),
 
+    RemoveOrphanedInstances,
+    RemoveOrphanedInstancesFound(Vec<String>),
+    RemoveOrphanedInstancesConfirm(Vec<String>),
+    RemoveOrphanedInstancesDone(Vec<String>),
+
     DefaultMinecraftWidthChanged(String),
     DefaultMinecraftHeightChanged(String),
     Rpc(RpcMessage),
@@ -414,6 +426,8 @@ pub enum NotesMessage {
 #[derive(Debug, Clone)]
 pub enum GameLogMessage {
     Action(widget::text_editor::Action),
+    /// `None` closes the search
+    Search(Option<String>),
     Copy,
     Upload,
     Uploaded(Res<String>),
@@ -442,6 +456,7 @@ pub enum MainMenuMessage {
     InstanceSelected(Instance),
     UsernameSet(String),
     SetInfoMessage(Option<InfoMessage>),
+    PropertiesLoaded(Instance, InstanceProperties),
 }
 
 #[derive(Debug, Clone)]
@@ -472,9 +487,16 @@ pub enum ModDescriptionMessage {
 #[derive(Debug, Clone)]
 pub enum LaunchMessage {
     Start,
-    End(Res<LaunchedProcess>),
+    /// The [`ProgressId`] is for the launch's
+    /// Java install progress (see [`crate::state::Launcher::java_recv`])
+    End(Res<LaunchedProcess>, ProgressId),
     Kill,
     GameExited(Res<(ExitStatus, Instance, Option<Diagnostic>)>),
+    /// The game was killed (not crashed)
+    GameStopped(Instance),
+    /// Write `eula=true` for the server and launch it again
+    AcceptEula(Instance),
+    AcceptEulaDone(Res),
 }
 
 #[derive(Debug, Clone)]
@@ -1,7 +1,7 @@
 use std::{
     collections::{HashMap, HashSet},
     fmt::Display,
-    path::Path,
+    path::{Path, PathBuf},
     sync::{
         Arc, Mutex,
         mpsc::{self, Receiver},
@@ -13,7 +13,8 @@ use iced::Task;
 use notify::Watcher;
 use ql_core::{
     GenericProgress, Instance, InstanceKind, IntoIoError, IntoStringError, IoError, JsonFileError,
-    LAUNCHER_CACHE_DIR, LAUNCHER_DIR, LAUNCHER_VERSION_NAME, LaunchedProcess, Progress, err,
+    LAUNCHER_CACHE_DIR, LAUNCHER_DIR, LAUNCHER_VERSION_NAME, LaunchedProcess, MultiProgress,
+    Progress, err,
     file_utils::{self, exists},
     read_log::LogLine,
     request::{CLIENT, build_middleware},
@@ -44,10 +45,54 @@ pub const NONE_JAR_NAME: &str = "(None)";
 
 type Res<T = ()> = Result<T, String>;
 
+/// Maximum number of lines kept in an [`InstanceLog`],
+/// older lines are removed past this.
+pub const LOG_LINE_LIMIT: usize = 10_000;
+
 pub struct InstanceLog {
     pub log: Vec<String>,
     pub has_crashed: bool,
     pub command: String,
+    /// Whether old lines were removed by [`InstanceLog::truncate_to`].
+    pub is_truncated: bool,
+}
+
+impl InstanceLog {
+    #[must_use]
+    pub fn line_count(&self) -> usize {
+        self.log.len()
+    }
+
+    /// Once there are more than `max` lines, removes the oldest
+    /// ones so that 90% of `max` remain. Returns whether any
+    /// lines were removed.
+    ///
+    /// Removing in chunks (rather than one line at a time)
+    /// means the log view, which has to be rebuilt after this,
+    /// isn't rebuilt on every new line once the log is full.
+    pub fn truncate_to(&mut self, max: usize) -> bool {
+        if self.log.len() <= max {
+            return false;
+        }
+        let excess = self.log.len() - max / 10 * 9;
+        self.log.drain(..excess);
+        self.is_truncated = true;
+        true
+    }
+
+    /// Returns the indices of log lines containing
+    /// `query` (case-insensitive).
+    pub fn search(&self, query: &str) -> Vec<usize> {
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let query = query.to_lowercase();
+        self.log
+            .iter()
+            .enumerate()
+            .filter_map(|(i, line)| line.to_lowercase().contains(&query).then_some(i))
+            .collect()
+    }
 }
 
 pub struct Launcher {
@@ -65,8 +110,13 @@ pub struct Launcher {
     pub discord_ipc_client: Option<PresenceClient>,
     pub discord_connection_state: Arc<Mutex<PresenceConnectionState>>,
 
-    pub java_recv: Option<ProgressBar<GenericProgress>>,
+    /// Java install progress while launching.
+    /// Multiple instances may be launching (and installing Java) at once.
+    pub java_recv: Option<MultiProgress<GenericProgress>>,
     pub custom_jar: Option<CustomJarState>,
+    /// The mod store, kept while a [`State::ConfirmAction`]
+    /// prompt is shown over it (so the search isn't lost).
+    pub mods_store_behind_prompt: Option<Box<MenuModsDownload>>,
     /// See [`AutoSaveKind`]
     pub autosave: HashSet<AutoSaveKind>,
 
@@ -125,9 +175,25 @@ impl CustomJarState {
 pub struct FsWatcher {
     recv: Receiver<notify::Event>,
     _watcher: notify::RecommendedWatcher,
+    /// If set, only changes to this file count.
+    file: Option<PathBuf>,
 }
 
 impl FsWatcher {
+    /// Watches a single file for changes.
+    ///
+    /// This watches the file's parent directory instead of
+    /// the file itself, because many text editors save by
+    /// writing a new file and renaming it over the old one,
+    /// which would silently break a watch on the file.
+    pub fn watch_file<P: AsRef<Path>>(path: P) -> notify::Result<FsWatcher> {
+        let path = path.as_ref();
+        let parent = path.parent().unwrap_or(path);
+        let mut watcher = Self::new(parent)?;
+        watcher.file = Some(path.to_owned());
+        Ok(watcher)
+    }
+
     pub fn new<P: AsRef<Path>>(path: P) -> notify::Result<FsWatcher> {
         let (tx, recv) = mpsc::channel();
 
@@ -143,12 +209,18 @@ impl FsWatcher {
         Ok(FsWatcher {
             recv,
             _watcher: watcher,
+            file: None,
         })
     }
 
     pub fn has_changed(&self) -> bool {
         let mut has_changed = false;
         while let Ok(event) = self.recv.try_recv() {
+            if let Some(file) = &self.file {
+                if !event.paths.iter().any(|n| n == file) {
+                    continue;
+                }
+            }
             if let notify::EventKind::Access(notify::event::AccessKind::Open(
                 notify::event::AccessMode::Any,
             )) = event.kind
@@ -178,10 +250,23 @@ pub struct GameProcess {
     pub server_input: Option<(ChildStdin, bool)>,
 }
 
+impl GameProcess {
+    /// Whether the process is still running,
+    /// not just being tracked.
+    ///
+    /// If the process is busy (being used elsewhere),
+    /// it's assumed to be running.
+    pub fn is_alive(&self) -> bool {
+        let Ok(mut child) = self.child.child.try_lock() else {
+            return true;
+        };
+        matches!(child.try_wait(), Ok(None))
+    }
+}
+
 impl Launcher {
     pub fn load_new(
-        is_new_user: bool,
-        config: Result<LauncherConfig, JsonFileError>,
+        config: Result<(LauncherConfig, bool), JsonFileError>,
     ) -> Result<Self, JsonFileError> {
         if let Err(err) = file_utils::get_launcher_dir() {
             err!("Could not get launcher dir (This is a bug):");
@@ -190,12 +275,13 @@ impl Launcher {
             )));
         }
 
-        let mut config = config?;
+        let (mut config, is_new_user) = config?;
+        // let is_new_user = true; // Uncomment to test the intro screen.
         let theme = config.c_theme();
         let (window_width, window_height) = config.c_window_size();
 
         let mut launch = MenuLaunch::default();
-        launch.resize_sidebar(SIDEBAR_WIDTH);
+        launch.resize_sidebar(SIDEBAR_WIDTH, window_width);
         let launch = State::Launch(launch);
 
         // The version field was added in 0.3
@@ -250,6 +336,7 @@ impl Launcher {
             server_watcher: None,
             java_recv: None,
             custom_jar: None,
+            mods_store_behind_prompt: None,
 
             logs: HashMap::new(),
             processes: HashMap::new(),
@@ -310,6 +397,7 @@ impl Launcher {
             server_watcher: None,
             selected_instance: None,
             custom_jar: None,
+            mods_store_behind_prompt: None,
 
             is_log_open: false,
             is_launching_game: false,
@@ -342,6 +430,14 @@ impl Launcher {
         self.selected_instance.as_ref().unwrap()
     }
 
+    /// Whether the selected instance is a server.
+    /// `false` if nothing is selected.
+    pub fn server_selected(&self) -> bool {
+        self.selected_instance
+            .as_ref()
+            .is_some_and(Instance::is_server)
+    }
+
     #[allow(clippy::needless_pass_by_value)]
     pub fn set_error(&mut self, error: impl ToString) {
         let error = error.to_string().replace(CLIENT_ID, "[CLIENT ID]");
@@ -351,9 +447,9 @@ impl Launcher {
 
     pub fn go_to_main_menu(&mut self, message: Option<InfoMessage>) -> Task<Message> {
         let mut menu_launch = MenuLaunch::new(message);
-        menu_launch.resize_sidebar(SIDEBAR_WIDTH);
+        menu_launch.resize_sidebar(SIDEBAR_WIDTH, self.window_state.size.0);
         let t = if let Some(inst) = &self.selected_instance {
-            menu_launch.reload_notes(inst.clone())
+            menu_launch.reload_instance_info(inst.clone())
         } else {
             Task::none()
         };
@@ -467,7 +563,8 @@ pub async fn get_entries(kind: InstanceKind) -> Res<(Vec<String>, InstanceKind)>
             .await
             .strerr()?
             .into_iter()
-            .filter(|n| !n.is_file)
+            // Skip broken symlinks, they aren't directories either
+            .filter(|n| !n.is_file && (!n.is_symlink || dir_path.join(&n.name).is_dir()))
             .map(|n| n.name)
             .collect(),
         kind,
@@ -479,6 +576,12 @@ pub struct ProgressBar<T: Progress> {
     pub message: Option<String>,
     pub receiver: Receiver<T>,
     pub progress: T,
+    /// If set, a cancel button is shown next to the bar
+    /// which sends this message.
+    ///
+    /// The handler should drop the task's abort handle
+    /// (from [`Task::abortable`]) to actually stop it.
+    pub cancel_message: Option<Message>,
 }
 
 impl<T: Default + Progress> ProgressBar<T> {
@@ -488,6 +591,7 @@ impl<T: Default + Progress> ProgressBar<T> {
             message: None,
             receiver,
             progress: T::default(),
+            cancel_message: None,
         }
     }
 
@@ -497,11 +601,17 @@ impl<T: Default + Progress> ProgressBar<T> {
             message: Some(msg),
             receiver,
             progress: T::default(),
+            cancel_message: None,
         }
     }
 }
 
 impl<T: Progress> ProgressBar<T> {
+    pub fn with_cancel(mut self, message: Message) -> Self {
+        self.cancel_message = Some(message);
+        self
+    }
+
     pub fn tick(&mut self) -> bool {
         let mut has_ticked = false;
         while let Ok(progress) = self.receiver.try_recv() {
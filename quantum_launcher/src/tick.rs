@@ -2,16 +2,17 @@ use std::{collections::HashMap, sync::Arc};
 
 use iced::{Rectangle, Task, widget::text_editor};
 use ql_core::{
-    Instance, IntoIoError, IntoJsonError, IntoStringError, JsonFileError, constants::OS_NAME,
-    json::InstanceConfigJson,
+    Instance, IntoIoError, IntoJsonError, IntoStringError, JsonFileError, constants::OS_NAME, err,
+    json::InstanceConfigJson, read_log::LogLineKind,
 };
 use ql_mod_manager::store::ModIndex;
 
 use crate::state::{
-    AutoSaveKind, EditInstanceMessage, GameProcess, InstallModsMessage, InstanceLog, LaunchModal,
-    LaunchTab, Launcher, LogState, ManageJarModsMessage, ManageModsMessage, MenuCreateInstance,
-    MenuEditMods, MenuExportInstance, MenuInstallFabric, MenuInstallOptifine, MenuLaunch,
-    MenuLoginMS, MenuModsDownload, MenuRecommendedMods, Message, State,
+    AutoSaveKind, EditInstanceMessage, FsWatcher, GameProcess, InstallModsMessage, InstanceLog,
+    LOG_LINE_LIMIT, LaunchModal, LaunchTab, Launcher, LogState, ManageJarModsMessage,
+    ManageModsMessage, MenuCreateInstance, MenuEditMods, MenuExportInstance, MenuInstallFabric,
+    MenuInstallOptifine, MenuLaunch, MenuLoginMS, MenuModsDownload, MenuRecommendedMods, Message,
+    State,
 };
 use crate::{config::SIDEBAR_WIDTH, state::InfoMessage};
 
@@ -28,6 +29,29 @@ impl Launcher {
 
                 let mut commands = Vec::new();
 
+                // `config.json` was edited outside the launcher.
+                // Only reload if there are no unsaved changes in the menu.
+                let config_edited_externally = if let State::Launch(MenuLaunch {
+                    edit_instance: Some(edit),
+                    tab: LaunchTab::Edit,
+                    ..
+                }) = &self.state
+                {
+                    edit.config_watcher
+                        .as_ref()
+                        .is_some_and(FsWatcher::has_changed)
+                        && self.autosave.contains(&AutoSaveKind::InstanceConfig)
+                        && self
+                            .selected_instance
+                            .as_ref()
+                            .is_some_and(|i| differs_from_disk(&edit.config, i))
+                } else {
+                    false
+                };
+                if config_edited_externally {
+                    self.load_edit_instance(None);
+                }
+
                 let edit_config = if let State::Launch(MenuLaunch {
                     edit_instance: Some(edit),
                     tab: LaunchTab::Edit,
@@ -53,25 +77,32 @@ impl Launcher {
                     } else {
                         &mut None
                     };
-                    Self::read_game_logs(
+                    let truncated = Self::read_game_logs(
                         process,
                         instance,
                         &mut self.logs,
                         log_state,
                         self.selected_instance.as_ref(),
                     );
+                    if truncated && self.selected_instance.as_ref() == Some(instance) {
+                        if let (State::Launch(menu), Some(log)) =
+                            (&mut self.state, self.logs.get(instance))
+                        {
+                            menu.refresh_log_search(log);
+                        }
+                    }
                 }
 
                 if let State::Launch(menu) = &self.state {
                     self.tick_sidebar_auto_scroll(menu, &mut commands);
                 }
-                self.autosave_launcher_config();
+                commands.push(self.autosave_launcher_config());
 
                 return Task::batch(commands);
             }
             State::Create(menu) => {
                 menu.tick();
-                self.autosave_launcher_config();
+                return self.autosave_launcher_config();
             }
             State::EditMods(menu) => {
                 let instance = self.selected_instance.as_ref().unwrap();
@@ -101,7 +132,7 @@ impl Launcher {
             State::InstallJava => {
                 let has_finished = if let Some(progress) = &mut self.java_recv {
                     progress.tick();
-                    progress.progress.has_finished
+                    progress.has_finished()
                 } else {
                     true
                 };
@@ -114,8 +145,7 @@ impl Launcher {
                 return MenuModsDownload::tick(self.selected_instance.clone().unwrap());
             }
             State::LauncherSettings(_) => {
-                let launcher_config = self.config.clone();
-                tokio::spawn(async move { launcher_config.save().await });
+                return self.autosave_launcher_config();
             }
             State::EditJarMods(menu) => {
                 if self.autosave.insert(AutoSaveKind::Jarmods) {
@@ -263,13 +293,24 @@ impl Launcher {
         ));
     }
 
-    fn autosave_launcher_config(&mut self) {
+    fn autosave_launcher_config(&mut self) -> Task<Message> {
         if self.autosave.insert(AutoSaveKind::LauncherConfig) {
-            let launcher_config = self.config.clone();
-            tokio::spawn(async move { launcher_config.save().await });
+            self.save_launcher_config()
+        } else {
+            Task::none()
         }
     }
 
+    fn save_launcher_config(&self) -> Task<Message> {
+        let launcher_config = self.config.clone();
+        Task::perform(async move { launcher_config.save_async().await }, |res| {
+            if let Err(error) = res {
+                err!("Couldn't save launcher config: {error}");
+            }
+            Message::Nothing
+        })
+    }
+
     fn autosave_instance_config(
         &self,
         config: InstanceConfigJson,
@@ -284,17 +325,27 @@ impl Launcher {
         commands.push(cmd);
     }
 
+    /// Moves new log lines from the game into `logs`
+    /// (and the log view, if `instance` is selected).
+    ///
+    /// Returns whether old lines were removed to stay under
+    /// [`LOG_LINE_LIMIT`], which shifts line indices.
     pub fn read_game_logs(
         process: &GameProcess,
         instance: &Instance,
         logs: &mut HashMap<Instance, InstanceLog>,
         log_state: &mut Option<LogState>,
         selected_instance: Option<&Instance>,
-    ) {
+    ) -> bool {
         let update_ui = selected_instance.is_some_and(|n| n == instance);
 
         while let Some(message) = process.receiver.as_ref().and_then(|n| n.try_recv().ok()) {
-            let message = message.to_string();
+            let message = if let LogLineKind::Info(_) = &message.kind {
+                // XML log events already have their own timestamp
+                message.to_string()
+            } else {
+                format!("[{}] {message}", message.get_time())
+            };
 
             logs.entry(instance.clone())
                 .or_insert_with(|| {
@@ -308,14 +359,13 @@ impl Launcher {
                     );
 
                     if update_ui {
-                        *log_state = Some(LogState {
-                            content: text_editor::Content::with_text(&log_start),
-                        });
+                        *log_state = Some(LogState::new(&log_start));
                     }
                     InstanceLog {
                         log: vec![log_start],
                         has_crashed: false,
                         command: String::new(),
+                        is_truncated: false,
                     }
                 })
                 .log
@@ -325,6 +375,21 @@ impl Launcher {
                 update_log_render_state(log_state.as_mut(), message);
             }
         }
+
+        let Some(log) = logs.get_mut(instance) else {
+            return false;
+        };
+        if !log.truncate_to(LOG_LINE_LIMIT) {
+            return false;
+        }
+        if update_ui {
+            if let Some(state) = log_state {
+                // Expensive, but `truncate_to` removes lines in
+                // chunks so this only happens every few thousand lines
+                state.content = text_editor::Content::with_text(&log.log.join(""));
+            }
+        }
+        true
     }
 
     async fn save_config(
@@ -365,15 +430,10 @@ impl MenuEditMods {
             }
         }
 
-        let t1 = if let Some(project_type) = self.file_data.content_watcher.tick() {
-            MenuEditMods::update_locally_installed_mods(
-                &self.file_data.mod_index,
-                instance,
-                project_type,
-            )
-        } else {
-            Task::none()
-        };
+        if let Some(project_type) = self.file_data.content_watcher.tick() {
+            self.file_data.local_mods_pending.insert(project_type);
+        }
+        let t1 = self.refresh_locally_installed_mods(instance);
 
         let t2 = if self.file_data.index_watcher.has_changed() {
             let i = instance.clone();
@@ -410,5 +470,23 @@ fn update_log_render_state(log_state: Option<&mut LogState>, mut message: String
         let content = &mut state.content;
         content.perform(Action::Move(Motion::DocumentEnd));
         content.perform(Action::Edit(Edit::Paste(Arc::new(message))));
+        // Stay on the search match instead of following new lines
+        state.select_match();
     }
 }
+
+/// Whether the `config.json` on disk is different from `config`.
+///
+/// Our own autosaves also trigger the file watcher,
+/// so this filters those out.
+fn differs_from_disk(config: &InstanceConfigJson, instance: &Instance) -> bool {
+    let path = instance.get_instance_path().join("config.json");
+    let Ok(text) = std::fs::read_to_string(&path) else {
+        return false;
+    };
+    // Half-written or invalid, wait for the next change
+    let Ok(on_disk) = serde_json::from_str::<serde_json::Value>(&text) else {
+        return false;
+    };
+    serde_json::to_value(config).is_ok_and(|n| n != on_disk)
+}
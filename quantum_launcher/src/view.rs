@@ -10,7 +10,7 @@ use crate::{
     icons,
     menu_renderer::{
         Element, FONT_MONO, tooltip, view_account_login, view_changelog, view_confirm, view_error,
-        view_log_upload_result,
+        view_log_upload_result, view_multi_progress,
     },
     state::{
         Launcher, MenuCreateInstance, MenuCreateInstanceChoosing, Message, State, WindowMessage,
@@ -138,7 +138,7 @@ impl Launcher {
             State::Error { error } => view_error(error),
             State::InstallFabric(menu) => menu.view(self.instance(), self.tick_timer),
             State::InstallJava => column![widget::text("Downloading Java").size(20)]
-                .push_maybe(self.java_recv.as_ref().map(|n| n.view()))
+                .push_maybe(self.java_recv.as_ref().map(view_multi_progress))
                 .padding(10)
                 .spacing(10)
                 .into(),
@@ -28,14 +28,18 @@ pub async fn launch(name: &str, timeout: f32, cli: &Cli) -> bool {
         return false;
     };
     let verbose = cli.verbose;
+    let timeout_duration = Duration::from_secs_f32(timeout);
     let handle = tokio::task::spawn(async move {
         child
-            .read_logs(Vec::new(), (!verbose).then(|| std::sync::mpsc::channel().0))
+            .read_logs(
+                Vec::new(),
+                (!verbose).then(|| std::sync::mpsc::channel().0),
+                Some(timeout_duration),
+            )
             .await
     });
     // Ok((ExitStatus::default(), instance, None))
 
-    let timeout_duration = Duration::from_secs_f32(timeout);
     let start_time = tokio::time::Instant::now();
 
     let sys = sysinfo::System::new_all();
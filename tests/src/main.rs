@@ -1,7 +1,7 @@
 use std::{fmt::Display, path::PathBuf, process::exit};
 
 use clap::Parser;
-use ql_core::{LAUNCHER_DIR, ListEntry, Loader, do_jobs, eeprintln, print::LogConfig};
+use ql_core::{LAUNCHER_DIR, ListEntry, Loader, do_jobs, eeprintln, err, print::LogConfig};
 use ql_instances::DownloadError;
 
 use crate::version::{VERSIONS_LWJGL2, VERSIONS_LWJGL3, Version};
@@ -28,13 +28,26 @@ struct Cli {
     )]
     skip_lwjgl3: bool,
     #[arg(long)]
-    #[arg(help = "How long to wait for a window, per instance, before giving up (default: 60).")]
+    #[arg(
+        help = "How long to wait for a window, per instance, before giving up (default: 60). $QL_TEST_TIMEOUT takes priority over this if set."
+    )]
     timeout: Option<f32>,
     #[arg(short, long)]
     #[arg(help = "See all the logs to diagnose issues")]
     verbose: bool,
 }
 impl Cli {
+    fn get_timeout(&self) -> f32 {
+        // Lets CI override the timeout without changing the command
+        if let Ok(value) = std::env::var("QL_TEST_TIMEOUT") {
+            match value.parse::<f32>() {
+                Ok(timeout) => return timeout,
+                Err(e) => err!("Ignoring invalid QL_TEST_TIMEOUT ({value:?}): {e}"),
+            }
+        }
+        self.timeout.unwrap_or(60.0)
+    }
+
     fn get_versions(&self) -> impl Iterator<Item = &Version> {
         VERSIONS_LWJGL2.iter().chain(
             (!self.skip_lwjgl3)
@@ -118,14 +131,28 @@ async fn try_version<'a>(
     let instance = ql_core::Instance::client(name);
     attempt(ql_mod_manager::loaders::uninstall_loader(instance.clone()).await);
     set_terminal(cli.verbose);
-    if !launch::launch(name, cli.timeout.unwrap_or(60.0), cli).await {
+    if !launch::launch(name, cli.get_timeout(), cli).await {
         fails.push((name, None));
     }
     for loader in loaders {
         println!("(Loader: {loader:?})");
-        if let Err(err) =
-            ql_mod_manager::loaders::install_specified_loader(instance.clone(), *loader, None, None)
-                .await
+        let loader_extra = if let Loader::OptiFine = loader {
+            let Some(path) = get_optifine_fixture(name) else {
+                println!("Skipping, no OptiFine installer in tests/fixtures/optifine/{name}.jar");
+                continue;
+            };
+            Some(path)
+        } else {
+            None
+        };
+        if let Err(err) = ql_mod_manager::loaders::install_specified_loader(
+            instance.clone(),
+            *loader,
+            None,
+            None,
+            loader_extra,
+        )
+        .await
         {
             eeprintln!("{err}");
             fails.push((name, Some(*loader)));
@@ -133,7 +160,7 @@ async fn try_version<'a>(
         }
 
         println!("Done");
-        if !launch::launch(name, cli.timeout.unwrap_or(60.0), cli).await {
+        if !launch::launch(name, cli.get_timeout(), cli).await {
             fails.push((name, Some(*loader)));
         }
         set_terminal(cli.verbose);
@@ -141,6 +168,18 @@ async fn try_version<'a>(
     }
 }
 
+/// OptiFine installers can't be redistributed, so they aren't
+/// checked in. Download them from <https://optifine.net/downloads>
+/// and place them in `tests/fixtures/optifine/<VERSION>.jar`.
+fn get_optifine_fixture(version: &str) -> Option<PathBuf> {
+    let path = PathBuf::from(file!())
+        .parent()?
+        .parent()?
+        .join("fixtures/optifine")
+        .join(format!("{version}.jar"));
+    path.is_file().then_some(path)
+}
+
 fn setup_dir() {
     let new_dir = PathBuf::from(file!())
         .parent()
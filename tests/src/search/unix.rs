@@ -3,6 +3,15 @@ use ql_core::err;
 use crate::search::kill_proc;
 
 pub fn search_for_window(pid: u32, sys: &sysinfo::System) -> bool {
+    #[cfg(target_os = "linux")]
+    if is_wayland_only() {
+        if has_gl_context(pid) && kill_proc(pid, sys) {
+            println!();
+            return true;
+        }
+        return false;
+    }
+
     if which::which("xdotool").is_err() {
         err!("xdotool isn't installed! Please install it first.");
         std::process::exit(1);
@@ -54,3 +63,33 @@ fn kill_process(pid: u32, sys: &sysinfo::System, n: std::process::Output) -> boo
     }
     false
 }
+
+/// Whether we're on a Wayland session where `xdotool`
+/// can't see the game's window (no XWayland, or no `xdotool`).
+#[cfg(target_os = "linux")]
+fn is_wayland_only() -> bool {
+    let is_set = |var: &str| std::env::var_os(var).is_some_and(|n| !n.is_empty());
+    is_set("WAYLAND_DISPLAY") && (!is_set("DISPLAY") || which::which("xdotool").is_err())
+}
+
+/// Wayland doesn't let other clients list windows,
+/// so instead check whether the game has loaded a GL driver.
+///
+/// LWJGL only does this when creating the window's
+/// OpenGL context, so it's a good enough substitute.
+#[cfg(target_os = "linux")]
+fn has_gl_context(pid: u32) -> bool {
+    const GL_DRIVERS: &[&str] = &[
+        "_dri.so",
+        "libgallium",
+        "libnvidia-glcore",
+        "libEGL_mesa",
+        "libGLX_mesa",
+    ];
+
+    let Ok(maps) = std::fs::read_to_string(format!("/proc/{pid}/maps")) else {
+        return false;
+    };
+    maps.lines()
+        .any(|line| GL_DRIVERS.iter().any(|driver| line.contains(driver)))
+}
@@ -6,7 +6,10 @@ const fn ver(name: &'static str) -> Version {
     Version(name, &[])
 }
 
-const FORGE_QUILT: [Loader; 1] = [Loader::Forge];
+const FORGE: [Loader; 1] = [Loader::Forge];
+const FORGE_QUILT: [Loader; 2] = [Loader::Forge, Loader::Quilt];
+// NeoForge only supports 1.20.2 and above
+const FORGE_QUILT_NEOFORGE: [Loader; 3] = [Loader::Forge, Loader::Quilt, Loader::NeoForge];
 
 pub const VERSIONS_LWJGL2: &[Version] = &[
     // last version of classic, should represent most early versions
@@ -16,23 +19,26 @@ pub const VERSIONS_LWJGL2: &[Version] = &[
     // last based on old launcher system
     Version("1.5.2", &[]),
     // after migration to new launcher system
-    Version("1.7.10", &FORGE_QUILT),
+    Version("1.7.10", &FORGE),
     // one of the most popular release versions
-    Version("1.8.9", &FORGE_QUILT),
+    Version("1.8.9", &FORGE),
     // last version to use lwjgl2
-    Version("1.12.2", &FORGE_QUILT),
+    Version("1.12.2", &[Loader::Forge, Loader::OptiFine]),
 ];
 
 pub const VERSIONS_LWJGL3: &[Version] = &[
     ver("inf-20100415-lwjgl3"),      // test of lwjgl3 backport
     Version("1.14.4", &FORGE_QUILT), // after migration to lwjgl3, engine rewrites
-    Version("1.16.5", &FORGE_QUILT), // last version to use Java 8, OpenGL 2.x
+    // last version to use Java 8, OpenGL 2.x
+    Version("1.16.5", &[Loader::Forge, Loader::OptiFine]),
     // after migration to Java 17, OpenGL 3.x, engine rewrites
     Version("1.18.2", &FORGE_QUILT),
+    // last version to use Java 17, early NeoForge
+    Version("1.20.4", &FORGE_QUILT_NEOFORGE),
     // has some weird bugs
-    Version("1.21.5", &[Loader::Forge, Loader::Quilt, Loader::NeoForge]),
+    Version("1.21.5", &FORGE_QUILT_NEOFORGE),
     // last launchwrapper version
-    Version("1.21.10", &[Loader::Forge, Loader::Quilt, Loader::NeoForge]),
+    Version("1.21.10", &FORGE_QUILT_NEOFORGE),
     // TODO: Wait for a version to come out after 1.21.11, then add it here
     // (BetterJSONs is, unfortunately, up-to-date so can't test without it)
 ];